@@ -12,6 +12,9 @@
 //!   Showcases the use of defining custom trait bounds.
 //
 
+// NOTE: We're testing the equality impls, so comparing the same expressions is the point
+#![allow(clippy::eq_op)]
+
 use std::cmp::Ordering;
 use std::hash::{DefaultHasher, Hasher};
 use std::marker::PhantomData;
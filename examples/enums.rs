@@ -12,13 +12,16 @@
 //!   Shows that the crate works for enums.
 //
 
+// NOTE: We're testing the equality impls, so comparing the same expressions is the point
+#![allow(clippy::eq_op)]
+
 use std::cmp::Ordering;
 use std::hash::{DefaultHasher, Hasher as _};
 use std::marker::PhantomData;
 
 #[cfg(feature = "serde")]
 use better_derive::Serialize;
-use better_derive::{Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd};
+use better_derive::{Clone, Debug, Default, Eq, From, Hash, Ord, PartialEq, PartialOrd, TryInto};
 
 
 /***** HELPER FUNCTIONS *****/
@@ -61,9 +64,10 @@ enum Baz {
 struct DontImplementAnything;
 
 /// Special struct with generics that don't have to be debug.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, From, Hash, Ord, PartialOrd, PartialEq, TryInto)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 enum PhantomEnum<T> {
+    #[default]
     Variant1 { _f: PhantomData<T> },
 }
 
@@ -83,6 +87,15 @@ fn main() {
 
 
 
+    assert_eq!(PhantomEnum::<DontImplementAnything>::default(), PhantomEnum::<DontImplementAnything>::Variant1 { _f: PhantomData });
+
+
+
+    assert_eq!(PhantomEnum::<DontImplementAnything>::from(PhantomData), PhantomEnum::<DontImplementAnything>::Variant1 { _f: PhantomData });
+    assert_eq!(PhantomData::try_from(PhantomEnum::<DontImplementAnything>::Variant1 { _f: PhantomData }), Ok(PhantomData));
+
+
+
     // NOTE: Can't construct, of course
     // assert_eq!(format!("{:?}", Foo), ???);
     // assert_eq!(format!("{:#?}", Foo), ???);
@@ -12,13 +12,16 @@
 //!   Shows that the crate works for structs.
 //
 
+// NOTE: We're testing the equality impls, so comparing the same expressions is the point
+#![allow(clippy::eq_op)]
+
 use std::cmp::Ordering;
 use std::hash::{DefaultHasher, Hasher as _};
 use std::marker::PhantomData;
 
 #[cfg(feature = "serde")]
 use better_derive::Serialize;
-use better_derive::{Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd};
+use better_derive::{Clone, Copy, Debug, Default, Eq, From, Hash, Ord, PartialEq, PartialOrd};
 
 
 /***** HELPER FUNCTIONS *****/
@@ -35,12 +38,12 @@ fn hash<T: std::hash::Hash>(obj: T) -> u64 {
 
 /***** EXAMPLES *****/
 /// Example unit struct as usual.
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialOrd, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 struct Foo;
 
 /// Example tuple struct as usual.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialOrd, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 struct Bar((), bool, String);
 
@@ -50,7 +53,7 @@ struct Bar((), bool, String);
 struct BarNewtype(bool);
 
 /// Example struct struct as usual.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialOrd, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 struct Baz {
     a: (),
@@ -63,7 +66,7 @@ struct Baz {
 struct DontImplementAnything;
 
 /// Special struct with generics that don't have to be debug.
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, From, Hash, Ord, PartialOrd, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 struct PhantomStruct<T> {
     _f: PhantomData<T>,
@@ -95,6 +98,16 @@ fn main() {
 
 
 
+    assert_eq!(Bar::default(), Bar((), false, String::new()));
+    assert_eq!(Baz::default(), Baz { a: (), b: false, c: String::new() });
+    assert_eq!(PhantomStruct::<DontImplementAnything>::default(), PhantomStruct::<DontImplementAnything> { _f: PhantomData });
+
+
+
+    assert_eq!(PhantomStruct::<DontImplementAnything>::from(PhantomData), PhantomStruct::<DontImplementAnything> { _f: PhantomData });
+
+
+
     assert_eq!(format!("{:?}", Foo), "Foo");
     assert_eq!(format!("{:#?}", Foo), "Foo");
     assert_eq!(format!("{:?}", Bar((), true, "Hello, world!".into())), "Bar((), true, \"Hello, world!\")");
@@ -0,0 +1,168 @@
+//  ACCESSORS.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 01:02:36
+//  Last edited:
+//    19 Oct 2026, 10:16:22
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Accessors`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Data, DeriveInput, Error, Fields, Ident, LitStr, Path, Token, Visibility, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, member, parse_field_attrs_with, parse_toplevel_attrs, std_path};
+
+
+/***** HELPERS *****/
+/// Defines the attributes of the `Accessors`-macro on fields.
+enum FieldAttr {
+    /// Returns the field by value instead of by reference.
+    Copy,
+    /// Gives the accessors another visibility.
+    Vis(Visibility),
+}
+impl Parse for FieldAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "copy" {
+            Ok(Self::Copy)
+        } else if ident == "vis" {
+            input.parse::<Token![=]>()?;
+            let lit: LitStr = input.parse()?;
+            Ok(Self::Vis(lit.parse()?))
+        } else {
+            Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Builds the accessors for all fields of a struct.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to build the accessors for.
+///
+/// # Returns
+/// A list of accessor methods.
+///
+/// # Errors
+/// This function fails if the input is not a struct, or if we failed to parse the fields'
+/// attributes.
+fn build_accessors(input: &DeriveInput) -> Result<Vec<TokenStream2>, Error> {
+    let fields: &Fields = match &input.data {
+        Data::Struct(s) => &s.fields,
+        Data::Enum(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), "The `Accessors` derive macro can only be used on structs")),
+    };
+
+    // Build the accessors for every field
+    let mut accessors: Vec<TokenStream2> = Vec::with_capacity(4 * fields.len());
+    for (i, f) in fields.iter().enumerate() {
+        let (skip, attrs) = parse_field_attrs_with::<FieldAttr>("accessors", &f.attrs)?;
+        if skip {
+            continue;
+        }
+        let mut copy: bool = false;
+        let mut vis: Visibility = Visibility::Public(Default::default());
+        for attr in attrs {
+            match attr {
+                FieldAttr::Copy => copy = true,
+                FieldAttr::Vis(v) => vis = v,
+            }
+        }
+
+        // Decide on the names. Unnamed fields get, e.g., `get_0()` as getter.
+        let (getter, getter_mut, setter, with): (Ident, Ident, Ident, Ident) = match &f.ident {
+            Some(ident) => (ident.clone(), format_ident!("{}_mut", ident), format_ident!("set_{}", ident), format_ident!("with_{}", ident)),
+            None => (format_ident!("get_{}", i), format_ident!("get_{}_mut", i), format_ident!("set_{}", i), format_ident!("with_{}", i)),
+        };
+
+        // Generate them
+        let member: TokenStream2 = member(fields, i);
+        let ty = &f.ty;
+        let getter_doc = format!("Returns the `{member}`-field.");
+        let getter_mut_doc = format!("Returns the `{member}`-field mutably.");
+        let setter_doc = format!("Sets the `{member}`-field.");
+        let with_doc = format!("Returns this value with the `{member}`-field set.");
+        accessors.push(if copy {
+            quote! {
+                #[doc = #getter_doc]
+                #[inline]
+                #vis fn #getter(&self) -> #ty where #ty: ::std::marker::Copy { self.#member }
+            }
+        } else {
+            quote! {
+                #[doc = #getter_doc]
+                #[inline]
+                #vis fn #getter(&self) -> &#ty { &self.#member }
+            }
+        });
+        accessors.push(quote! {
+            #[doc = #getter_mut_doc]
+            #[inline]
+            #vis fn #getter_mut(&mut self) -> &mut #ty { &mut self.#member }
+
+            #[doc = #setter_doc]
+            #[inline]
+            #vis fn #setter(&mut self, __value: #ty) { self.#member = __value; }
+
+            #[doc = #with_doc]
+            #[inline]
+            #vis fn #with(mut self, __value: #ty) -> Self {
+                self.#member = __value;
+                self
+            }
+        });
+    }
+    Ok(accessors)
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Accessors` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn accessors(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Build the methods first
+    let accessors = match build_accessors(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the impl. No need to bound anything, as we only refer to fields.
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("accessors", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("marker", "Sized");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // Done, build the impl
+    let name = &input.ident;
+    quote! {
+        impl #impl_gen #name #ty_gen #where_clause {
+            #(#accessors)*
+        }
+    }
+    .into()
+}
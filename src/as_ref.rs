@@ -0,0 +1,329 @@
+//  AS REF.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 18:40:33
+//  Last edited:
+//    19 Oct 2026, 09:58:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `AsRef`-, `AsMut`- and `Borrow`-macros.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens as _, quote};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::{Attribute, Data, DeriveInput, Error, Field, GenericArgument, Ident, Meta, Path, Token, Type, parse_macro_input};
+
+use crate::common::{
+    NoCustomAttrs, extract_generics_with, find_bare_type_param_conflict, member, parse_field_attrs, parse_toplevel_attrs, path_with_args, std_path,
+    trait_bounds,
+};
+
+
+/***** HELPERS *****/
+/// Defines which of the three macros we're deriving.
+#[derive(Clone, Copy)]
+enum Kind {
+    /// `AsRef`
+    AsRef,
+    /// `AsMut`
+    AsMut,
+    /// `Borrow`
+    Borrow,
+}
+impl Kind {
+    /// Returns the name of the trait.
+    #[inline]
+    fn trait_name(self) -> &'static str {
+        match self {
+            Self::AsRef => "AsRef",
+            Self::AsMut => "AsMut",
+            Self::Borrow => "Borrow",
+        }
+    }
+
+    /// Returns the name of the attribute marking fields.
+    #[inline]
+    fn attr_name(self) -> &'static str {
+        match self {
+            Self::AsRef => "as_ref",
+            Self::AsMut => "as_mut",
+            Self::Borrow => "borrow",
+        }
+    }
+
+    /// Returns the name of the trait's method.
+    #[inline]
+    fn method_name(self) -> &'static str {
+        match self {
+            Self::AsRef => "as_ref",
+            Self::AsMut => "as_mut",
+            Self::Borrow => "borrow",
+        }
+    }
+
+    /// Builds the path to the trait, optionally with a target type as argument.
+    ///
+    /// # Arguments
+    /// - `target`: The target type to give as generic argument, if any.
+    ///
+    /// # Returns
+    /// A [`Path`] referring to, e.g., `::std::convert::AsRef<target>`.
+    fn path(self, target: Option<&Type>) -> Path {
+        let path: Path = match self {
+            Self::AsRef => std_path("convert", "AsRef"),
+            Self::AsMut => std_path("convert", "AsMut"),
+            Self::Borrow => std_path("borrow", "Borrow"),
+        };
+        match target {
+            Some(target) => path_with_args(path, Some(GenericArgument::Type(target.clone()))),
+            None => path,
+        }
+    }
+}
+
+
+
+/// Defines a single impl to generate.
+struct Target<'f> {
+    /// The index of the field to refer to.
+    field: usize,
+    /// The type of the field.
+    field_ty: &'f Type,
+    /// The type to refer to. If this is not the field's type, we forward through it.
+    target: Option<Type>,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Parses `#[as_ref]` or `#[as_ref(TYPE, ...)]` (or the equivalent of the other macros) on field
+/// attributes.
+///
+/// # Arguments
+/// - `kind`: The [`Kind`] of macro we're parsing for.
+/// - `attrs`: The list of [`Attribute`]s to parse.
+///
+/// # Returns
+/// [`None`] if the field isn't marked, or else a list of types to forward to. If this list is
+/// empty, the field's own type is meant.
+///
+/// # Errors
+/// This function fails if we failed to parse the list of types.
+fn parse_field_attr(kind: Kind, attrs: &[Attribute]) -> Result<Option<Vec<Type>>, Error> {
+    let mut res: Option<Vec<Type>> = None;
+    for attr in attrs {
+        match &attr.meta {
+            Meta::Path(p) if p.is_ident(kind.attr_name()) => {
+                res.get_or_insert_with(Vec::new);
+            },
+            Meta::List(l) if l.path.is_ident(kind.attr_name()) => {
+                let tys: Punctuated<Type, Token![,]> = attr.parse_args_with(Punctuated::parse_terminated)?;
+                res.get_or_insert_with(Vec::new).extend(tys);
+            },
+
+            // Anything else, we ignore
+            _ => continue,
+        }
+    }
+    Ok(res)
+}
+
+/// Collects the impls to generate.
+///
+/// # Arguments
+/// - `kind`: The [`Kind`] of macro we're generating.
+/// - `input`: The [`DeriveInput`] to collect the impls for.
+///
+/// # Returns
+/// A list of [`Target`]s, one for every impl to generate.
+///
+/// # Errors
+/// This function fails if the input is not a struct, if no field is eligible, if two impls would
+/// conflict (including a bare type parameter and any other type) or, for `Borrow`, if the
+/// `Hash`- and `PartialEq`-impls consider other fields.
+fn collect_targets(kind: Kind, input: &DeriveInput) -> Result<Vec<Target<'_>>, Error> {
+    let trait_name: &str = kind.trait_name();
+    let s = match &input.data {
+        Data::Struct(s) => s,
+        Data::Enum(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), format!("The `{trait_name}` derive macro can only be used on structs"))),
+    };
+
+    // Find the marked fields
+    let mut targets: Vec<Target> = Vec::new();
+    let mut marked: Vec<&Field> = Vec::new();
+    for (i, f) in s.fields.iter().enumerate() {
+        match parse_field_attr(kind, &f.attrs)? {
+            Some(tys) if tys.is_empty() => targets.push(Target { field: i, field_ty: &f.ty, target: None }),
+            Some(tys) => targets.extend(tys.into_iter().map(|ty| Target { field: i, field_ty: &f.ty, target: Some(ty) })),
+            None => continue,
+        }
+        marked.push(f);
+    }
+
+    // If there are none, fall back to the only field
+    if marked.is_empty() {
+        if s.fields.len() != 1 {
+            return Err(Error::new(
+                input.ident.span(),
+                format!(
+                    "Cannot derive `{trait_name}` for a struct without precisely one field (mark the field(s) to refer to with `#[{}]`)",
+                    kind.attr_name()
+                ),
+            ));
+        }
+        let f: &Field = s.fields.iter().next().unwrap();
+        targets.push(Target { field: 0, field_ty: &f.ty, target: None });
+        marked.push(f);
+    }
+
+    // Assert we don't generate conflicting impls
+    // NOTE: We compare the types syntactically, which is the best we can do here
+    let mut seen: Vec<String> = Vec::with_capacity(targets.len());
+    for target in &targets {
+        let ty: &Type = target.target.as_ref().unwrap_or(target.field_ty);
+        let ty_str: String = ty.to_token_stream().to_string();
+        if seen.contains(&ty_str) {
+            return Err(Error::new(ty.span(), format!("Cannot generate multiple `{trait_name}`-impls for the same type")));
+        }
+        seen.push(ty_str);
+    }
+    let tys: Vec<&Type> = targets.iter().map(|target| target.target.as_ref().unwrap_or(target.field_ty)).collect();
+    if let Some((i, _, param)) = find_bare_type_param_conflict(&tys, &input.generics.params) {
+        return Err(Error::new(
+            tys[i].span(),
+            format!("Cannot generate an impl of `{trait_name}` for the bare type parameter `{param}`, as it may be any of the other types"),
+        ));
+    }
+
+    // For `Borrow`, assert that the field determines the `Hash`- and `PartialEq`-impls
+    // NOTE: This is a best-effort check, as we can only see the attributes. We don't know whether
+    // those traits are derived by us (or at all), nor do we check `Ord` and friends.
+    if let Kind::Borrow = kind {
+        if marked.len() > 1 {
+            return Err(Error::new(marked[1].span(), "Only one field can be marked as `#[borrow]`"));
+        }
+        for (i, f) in s.fields.iter().enumerate() {
+            if i == targets[0].field {
+                continue;
+            }
+            if !parse_field_attrs("hash", &f.attrs)? || !parse_field_attrs("partial_eq", &f.attrs)? {
+                return Err(Error::new(
+                    f.span(),
+                    "Fields that aren't borrowed must be skipped by `Hash` and `PartialEq` (e.g., `#[better_derive(skip)]`), or else `Borrow` \
+                     would be inconsistent with them",
+                ));
+            }
+        }
+    }
+    Ok(targets)
+}
+
+/// Implements one of the three macros.
+///
+/// # Arguments
+/// - `kind`: The [`Kind`] of macro to derive.
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impls.
+fn derive(kind: Kind, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Collect the impls to generate
+    let targets = match collect_targets(kind, &input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>(kind.attr_name(), &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Generate them
+    let name = &input.ident;
+    let method = Ident::new(kind.method_name(), Span::call_site());
+    let fields = match &input.data {
+        Data::Struct(s) => &s.fields,
+        _ => unreachable!(),
+    };
+    let impls = targets.into_iter().map(|Target { field, field_ty, target }| {
+        let member: TokenStream2 = member(fields, field);
+        let (ty, body, bounds): (&Type, TokenStream2, Vec<_>) = match &target {
+            // Forward through the field's own impl, which we then have to bound
+            Some(target) => {
+                let path: Path = kind.path(Some(target));
+                let body: TokenStream2 = match kind {
+                    Kind::AsMut => quote! { <#field_ty as #path>::#method(&mut self.#member) },
+                    Kind::AsRef | Kind::Borrow => quote! { <#field_ty as #path>::#method(&self.#member) },
+                };
+                (target, body, vec![(field_ty, trait_bounds(&path))])
+            },
+            // Just refer to the field directly
+            None => {
+                let body: TokenStream2 = match kind {
+                    Kind::AsMut => quote! { &mut self.#member },
+                    Kind::AsRef | Kind::Borrow => quote! { &self.#member },
+                };
+                (field_ty, body, vec![])
+            },
+        };
+
+        // Build the impl
+        let path: Path = kind.path(Some(ty));
+        let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &path, bounds);
+        let signature: TokenStream2 = match kind {
+            Kind::AsMut => quote! { fn #method(&mut self) -> &mut #ty },
+            Kind::AsRef | Kind::Borrow => quote! { fn #method(&self) -> &#ty },
+        };
+        quote! {
+            impl #impl_gen #path for #name #ty_gen #where_clause {
+                #[inline]
+                #signature { #body }
+            }
+        }
+    });
+    quote! { #(#impls)* }.into()
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `AsRef` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+#[inline]
+pub fn as_ref(input: TokenStream) -> TokenStream { derive(Kind::AsRef, input) }
+
+/// Actual implementation of the `AsMut` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+#[inline]
+pub fn as_mut(input: TokenStream) -> TokenStream { derive(Kind::AsMut, input) }
+
+/// Actual implementation of the `Borrow` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+#[inline]
+pub fn borrow(input: TokenStream) -> TokenStream { derive(Kind::Borrow, input) }
@@ -0,0 +1,273 @@
+//  BUILDER.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 23:51:14
+//  Last edited:
+//    19 Oct 2026, 10:15:07
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Builder`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, Meta, Path, Token, Type, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, parse_toplevel_attrs, std_path, trait_bounds};
+
+
+/***** HELPERS *****/
+/// Defines how a particular field is built.
+#[derive(Clone, Copy, Default)]
+struct FieldAttrs {
+    /// Whether the field falls back to its type's `Default`-impl if it isn't given.
+    default: bool,
+    /// Whether the setter accepts anything that converts `Into` the field's type.
+    into:    bool,
+    /// Whether the field doesn't get a setter at all, and is always initialized by default.
+    skip:    bool,
+}
+
+
+
+/// Defines a single field of the builder.
+struct BuilderField<'f> {
+    /// The name of the field.
+    ident: &'f Ident,
+    /// The type of the field.
+    ty:    &'f Type,
+    /// How to build the field.
+    attrs: FieldAttrs,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Parses `#[builder(default, into, skip)]` on field attributes.
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to parse.
+///
+/// # Returns
+/// The [`FieldAttrs`] describing how to build the field.
+///
+/// # Errors
+/// This function fails if we failed to parse the attributes, or if an unknown one was given.
+fn parse_field_attrs(attrs: &[Attribute]) -> Result<FieldAttrs, Error> {
+    let mut res = FieldAttrs::default();
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(l) if l.path.is_ident("builder") => {
+                for ident in attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)? {
+                    if ident == "default" {
+                        res.default = true;
+                    } else if ident == "into" {
+                        res.into = true;
+                    } else if ident == "skip" {
+                        res.skip = true;
+                    } else {
+                        return Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")));
+                    }
+                }
+            },
+
+            // Anything else, we ignore
+            _ => continue,
+        }
+    }
+    Ok(res)
+}
+
+/// Collects the fields of the builder.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to build a builder for.
+///
+/// # Returns
+/// A list of [`BuilderField`]s, one for every field of the struct.
+///
+/// # Errors
+/// This function fails if the input is not a struct with named fields, or if we failed to parse
+/// the fields' attributes.
+fn collect_fields(input: &DeriveInput) -> Result<Vec<BuilderField<'_>>, Error> {
+    let fields = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(fields) => &fields.named,
+            Fields::Unnamed(_) | Fields::Unit => {
+                return Err(Error::new(input.ident.span(), "The `Builder` derive macro can only be used on structs with named fields"));
+            },
+        },
+        Data::Enum(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), "The `Builder` derive macro can only be used on structs with named fields")),
+    };
+
+    // Parse the attributes of all fields
+    let mut res: Vec<BuilderField> = Vec::with_capacity(fields.len());
+    for f in fields {
+        res.push(BuilderField { ident: f.ident.as_ref().unwrap(), ty: &f.ty, attrs: parse_field_attrs(&f.attrs)? });
+    }
+    Ok(res)
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Builder` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the builder, its error type and their impls.
+pub fn builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Collect the fields
+    let fields = match collect_fields(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("builder", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics. Only `build()` needs bounds, and then only on the fields that are
+    // initialized by default.
+    let target: Path = std_path("default", "Default");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+    let (_, _, build_where_clause) = extract_generics_with(
+        &toplevel,
+        &input,
+        &target,
+        fields.iter().filter(|f| f.attrs.default || f.attrs.skip).map(|f| (f.ty, trait_bounds(&target))),
+    );
+
+    // Generate the builder's fields and setters
+    let mut builder_fields: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    let mut builder_inits: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    let mut setters: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    for BuilderField { ident, ty, attrs } in &fields {
+        if attrs.skip {
+            continue;
+        }
+        builder_fields.push(quote! { #ident: ::std::option::Option<#ty> });
+        builder_inits.push(quote! { #ident: ::std::option::Option::None });
+
+        let doc = format!("Sets the value of the `{ident}`-field.");
+        setters.push(if attrs.into {
+            quote! {
+                #[doc = #doc]
+                #[inline]
+                pub fn #ident<__V: ::std::convert::Into<#ty>>(mut self, __value: __V) -> Self {
+                    self.#ident = ::std::option::Option::Some(::std::convert::Into::into(__value));
+                    self
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #doc]
+                #[inline]
+                pub fn #ident(mut self, __value: #ty) -> Self {
+                    self.#ident = ::std::option::Option::Some(__value);
+                    self
+                }
+            }
+        });
+    }
+
+    // Generate the body of `build()`, which first asserts all required fields are given
+    let required: Vec<&Ident> = fields.iter().filter(|f| !f.attrs.default && !f.attrs.skip).map(|f| f.ident).collect();
+    let required_names: Vec<String> = required.iter().map(|ident| ident.to_string()).collect();
+    let inits = fields.iter().map(|BuilderField { ident, ty, attrs }| {
+        if attrs.skip {
+            quote! { #ident: <#ty as ::std::default::Default>::default() }
+        } else if attrs.default {
+            quote! { #ident: ::std::option::Option::unwrap_or_else(self.#ident, <#ty as ::std::default::Default>::default) }
+        } else {
+            quote! { #ident }
+        }
+    });
+    let name = &input.ident;
+    let error_name = format_ident!("{}BuilderError", name);
+    let build_body: TokenStream2 = if required.is_empty() {
+        quote! { ::std::result::Result::Ok(#name { #(#inits),* }) }
+    } else {
+        quote! {
+            match (#(self.#required),*,) {
+                (#(::std::option::Option::Some(#required)),*,) => ::std::result::Result::Ok(#name { #(#inits),* }),
+                (#(#required),*,) => {
+                    let mut __missing: ::std::vec::Vec<&'static str> = ::std::vec::Vec::new();
+                    #(if ::std::option::Option::is_none(&#required) { __missing.push(#required_names); })*
+                    ::std::result::Result::Err(#error_name { missing: __missing })
+                },
+            }
+        }
+    };
+
+    // Prepare the builder's definition
+    let vis = &input.vis;
+    let sname = name.to_string();
+    let builder_name = format_ident!("{}Builder", name);
+    let builder_doc = format!("A builder for a [`{sname}`].");
+    let error_doc = format!("Error returned when building a [`{sname}`] without all of its required fields.");
+    let generics = &input.generics;
+    let def_where_clause = &input.generics.where_clause;
+
+    // Done, build the impls
+    quote! {
+        #[doc = #builder_doc]
+        #vis struct #builder_name #generics #def_where_clause {
+            #(#builder_fields,)*
+            __marker: ::std::marker::PhantomData<fn() -> #name #ty_gen>,
+        }
+        impl #impl_gen ::std::default::Default for #builder_name #ty_gen #where_clause {
+            #[inline]
+            fn default() -> Self { Self::new() }
+        }
+        impl #impl_gen #builder_name #ty_gen #where_clause {
+            /// Creates a new builder without any of the fields set.
+            #[inline]
+            pub fn new() -> Self { Self { #(#builder_inits,)* __marker: ::std::marker::PhantomData } }
+
+            #(#setters)*
+        }
+        impl #impl_gen #builder_name #ty_gen #build_where_clause {
+            /// Builds the value from the fields set.
+            ///
+            /// # Errors
+            /// This function fails if any of the required fields wasn't set.
+            #[inline]
+            pub fn build(self) -> ::std::result::Result<#name #ty_gen, #error_name> { #build_body }
+        }
+
+        impl #impl_gen #name #ty_gen #where_clause {
+            /// Returns a builder for this type.
+            #[inline]
+            pub fn builder() -> #builder_name #ty_gen { #builder_name::new() }
+        }
+
+        #[doc = #error_doc]
+        #[derive(::std::clone::Clone, ::std::fmt::Debug, ::std::cmp::Eq, ::std::cmp::PartialEq)]
+        #vis struct #error_name {
+            /// The names of the required fields that weren't set.
+            pub missing: ::std::vec::Vec<&'static str>,
+        }
+        impl ::std::fmt::Display for #error_name {
+            #[inline]
+            fn fmt(&self, __f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::write!(__f, ::std::concat!("Cannot build ", #sname, " without a value for field(s) {}"), self.missing.join(", "))
+            }
+        }
+        impl ::std::error::Error for #error_name {}
+    }
+    .into()
+}
@@ -150,9 +150,9 @@ pub fn clone(input: TokenStream) -> TokenStream {
         leading_colon: Some(Default::default()),
         segments:      {
             let mut segments = Punctuated::new();
-            segments.push(PathSegment { ident: Ident::new("std".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("clone".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("Clone".into(), Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("clone", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("Clone", Span::call_site()), arguments: PathArguments::None });
             segments
         },
     }) {
@@ -4,7 +4,7 @@
 //  Created:
 //    09 Jan 2025, 01:10:02
 //  Last edited:
-//    06 Feb 2025, 15:33:18
+//    19 Oct 2026, 09:58:40
 //  Auto updated?
 //    Yes
 //
@@ -12,22 +12,22 @@
 //!   Defines common functionality between the macros.
 //
 
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{ToTokens, quote};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned as _;
 use syn::visit::Visit;
 use syn::{
-    AngleBracketedGenericArguments, Attribute, Data, DeriveInput, Error, Expr, ExprPath, Field, Fields, GenericArgument, GenericParam, Generics,
-    Ident, Meta, Path, PathArguments, PathSegment, PredicateType, Token, TraitBound, TraitBoundModifier, Type, TypeParamBound, TypePath,
-    WherePredicate, parenthesized,
+    AngleBracketedGenericArguments, Attribute, Data, DeriveInput, Error, Expr, ExprLit, ExprPath, ExprUnary, Field, Fields, GenericArgument, GenericParam, Generics,
+    Ident, Index, Lit, Meta, Path, PathArguments, PathSegment, PredicateType, Token, TraitBound, TraitBoundModifier, Type, TypeGroup, TypeParamBound, TypeParen,
+    TypePath, UnOp, Variant, WherePredicate, parenthesized,
 };
 
 
 /***** CONSTANTS *****/
 /// The name of the generic, cross-trait attribute.
-pub const COMMON_ATTR_NAME: &'static str = "better_derive";
+pub const COMMON_ATTR_NAME: &str = "better_derive";
 
 
 
@@ -73,78 +73,50 @@ fn gen_param_to_arg(param: &GenericParam) -> GenericArgument {
 
 
 
-/// Parses the data-level attributes in search of answers.
+/// Parses `#[SOME_IDENT(skip)]` on field (or variant) attributes.
 ///
 /// # Arguments
-/// - `base_ident`: The name of any attributes to look for (e.g., `debug`). Note that the
+/// - `base_ident`: The initialization of `SOME_IDENT`. Note that the
 ///   [common attribute](COMMON_ATTR_NAME) is always included.
-/// - `attrs`: The list of [`Attribute`]s to parse.
+/// - `attrs`: The list of [`Attribute`]s to skip.
 ///
 /// # Returns
-/// A list of params and a matching where clause that shalt be the bound for this impl, or [`None`]
-/// if none were given (and automatic derivation should be used).
+/// True if the skip was found, false otherwise.
 ///
 /// # Errors
-/// Note that this function can error if an attribute belonging to th(i|e)s(e) macro(s) was given,
-/// but we failed to understand it.
-fn parse_toplevel_attrs(base_ident: &str, attrs: &[Attribute]) -> Result<ToplevelAttrs, Error> {
-    let mut impl_gen: Option<Punctuated<GenericParam, Token![,]>> = None;
-    let mut ty_gen: Option<Punctuated<GenericArgument, Token![,]>> = None;
-    let mut where_clause: Option<Punctuated<WherePredicate, Token![,]>> = None;
-    for attr in attrs {
-        match &attr.meta {
-            Meta::List(l) if l.path.is_ident(COMMON_ATTR_NAME) || l.path.is_ident(base_ident) => {
-                // Parse the contents of the list as a further set of metas
-                let attrs: Punctuated<ToplevelAttr, Token![,]> = Attribute::parse_args_with(attr, Punctuated::parse_terminated)?;
-                for attr in attrs {
-                    match attr {
-                        ToplevelAttr::ImplGen(params) => {
-                            impl_gen = Some(params);
-                        },
-                        ToplevelAttr::TypeGen(args) => {
-                            ty_gen = Some(args);
-                        },
-                        ToplevelAttr::WhereClause(preds) => {
-                            where_clause = Some(preds);
-                        },
-                    }
-                }
-            },
-
-            // Anything else, we ignore
-            _ => continue,
-        }
-    }
-
-    // Return appropriately
-    Ok(ToplevelAttrs { impl_gen, ty_gen, where_clause })
+/// This function fails if it could not parse the contents of a matching [`Meta::List`], or if it
+/// contained anything but `skip`.
+#[inline]
+pub fn parse_field_attrs(base_ident: &str, attrs: &[Attribute]) -> Result<bool, Error> {
+    parse_field_attrs_with::<NoCustomAttrs>(base_ident, attrs).map(|(skip, _)| skip)
 }
 
-/// Parses `#[SOME_IDENT(skip)]` on field attributes.
+/// Parses `#[SOME_IDENT(skip, ...)]` on field (or variant) attributes, where `...` are
+/// macro-specific attributes.
 ///
 /// # Arguments
 /// - `base_ident`: The initialization of `SOME_IDENT`. Note that the
 ///   [common attribute](COMMON_ATTR_NAME) is always included.
-/// - `attrs`: The list of [`Attribute`]s to skip.
+/// - `attrs`: The list of [`Attribute`]s to parse.
 ///
 /// # Returns
-/// True if the skip was found, false otherwise.
+/// Whether the skip was found, and a list of any macro-specific attributes (`T`) found.
 ///
 /// # Errors
 /// This function fails if it could not parse the contents of a matching [`Meta::List`] as a list
-/// of metas.
-fn parse_field_attrs(base_ident: &str, attrs: &[Attribute]) -> Result<bool, Error> {
+/// of `skip` or `T`s.
+pub fn parse_field_attrs_with<T: Parse>(base_ident: &str, attrs: &[Attribute]) -> Result<(bool, Vec<T>), Error> {
+    let mut skip: bool = false;
+    let mut custom: Vec<T> = Vec::new();
     for attr in attrs {
         match &attr.meta {
             Meta::List(l) if l.path.is_ident(COMMON_ATTR_NAME) || l.path.is_ident(base_ident) => {
-                // Parse the contents of the list as a further set of metas
-                let attrs: Punctuated<Meta, Token![,]> = Attribute::parse_args_with(attr, Punctuated::parse_terminated)?;
-                for meta in attrs {
-                    match meta {
-                        Meta::Path(p) if p.is_ident("skip") => return Ok(true),
-
-                        // Anything else we will complain about
-                        meta => return Err(Error::new(meta.span(), format!("Unknown attribute {:?}", meta.path()))),
+                // Parse the contents of the list as a further set of attributes
+                let attrs: Punctuated<FieldAttr<T>, Token![,]> = Attribute::parse_args_with(attr, Punctuated::parse_terminated)?;
+                for attr in attrs {
+                    match attr {
+                        FieldAttr::Skip => skip = true,
+                        FieldAttr::Custom(attr) => custom.push(attr),
                     }
                 }
             },
@@ -153,32 +125,34 @@ fn parse_field_attrs(base_ident: &str, attrs: &[Attribute]) -> Result<bool, Erro
             _ => continue,
         }
     }
-    Ok(false)
+    Ok((skip, custom))
 }
 
 
 
 
 /***** HELPERS *****/
-/// Defines a collection of all information we parse toplevel.
-struct ToplevelAttrs {
-    impl_gen: Option<Punctuated<GenericParam, Token![,]>>,
-    ty_gen: Option<Punctuated<GenericArgument, Token![,]>>,
-    where_clause: Option<Punctuated<WherePredicate, Token![,]>>,
-}
-
 /// Defines a parsable attribute for the toplevel.
-enum ToplevelAttr {
+///
+/// Anything that isn't one of the generic-related attributes is parsed as `T`, which allows macros
+/// to define toplevel attributes of their own.
+enum ToplevelAttr<T> {
     /// The impl type generics.
     ImplGen(Punctuated<GenericParam, Token![,]>),
     /// The type-attached generics.
     TypeGen(Punctuated<GenericArgument, Token![,]>),
     /// The user is defining type constraints.
     WhereClause(Punctuated<WherePredicate, Token![,]>),
+    /// Some macro-specific attribute.
+    Custom(T),
 }
-impl Parse for ToplevelAttr {
+impl<T: Parse> Parse for ToplevelAttr<T> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let ident: Ident = input.parse()?;
+        // Only consume the identifier if it's one of ours
+        let ident: Ident = match input.fork().parse::<Ident>() {
+            Ok(ident) if ident == "impl_gen" || ident == "type_gen" || ident == "bound" || ident == "bounds" => input.parse()?,
+            _ => return Ok(Self::Custom(input.parse()?)),
+        };
         if ident == "impl_gen" {
             // Parse the equals sign and then the type generics clauses
             input.parse::<Token![=]>()?;
@@ -197,7 +171,32 @@ impl Parse for ToplevelAttr {
             parenthesized!(content in input);
             Ok(Self::WhereClause(Punctuated::parse_terminated(&content)?))
         } else {
-            Err(input.error(format!("Unknown attribute {ident:?}")))
+            unreachable!()
+        }
+    }
+}
+
+
+
+/// Defines the attributes that can be given on fields (or variants).
+///
+/// Anything that isn't `skip` is parsed as `T`, which allows macros to define field attributes of
+/// their own.
+enum FieldAttr<T> {
+    /// The field should be skipped.
+    Skip,
+    /// Some macro-specific attribute.
+    Custom(T),
+}
+impl<T: Parse> Parse for FieldAttr<T> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Only consume the identifier if it's ours
+        match input.fork().parse::<Ident>() {
+            Ok(ident) if ident == "skip" => {
+                input.parse::<Ident>()?;
+                Ok(Self::Skip)
+            },
+            _ => Ok(Self::Custom(input.parse()?)),
         }
     }
 }
@@ -252,8 +251,37 @@ impl<'ast, 'g> Visit<'ast> for HasGenericsVisitor<'g> {
 
 
 /***** AUXILLARY *****/
+/// Defines a collection of all generic-related information we parse toplevel.
+#[derive(Clone)]
+pub struct ToplevelAttrs {
+    impl_gen: Option<Punctuated<GenericParam, Token![,]>>,
+    ty_gen: Option<Punctuated<GenericArgument, Token![,]>>,
+    where_clause: Option<Punctuated<WherePredicate, Token![,]>>,
+}
+
+/// Defines the toplevel attributes of macros that don't accept any of their own.
+pub enum NoCustomAttrs {}
+impl Parse for NoCustomAttrs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        Err(input.error(format!("Unknown attribute {ident:?}")))
+    }
+}
+
 /// Helper type for correctly serializing a list of generic parameters.
 pub struct ImplGen(Punctuated<GenericParam, Token![,]>);
+impl ImplGen {
+    /// Adds an additional parameter to the generics.
+    ///
+    /// Lifetimes are added in front, as Rust requires, and anything else at the back.
+    ///
+    /// # Arguments
+    /// - `param`: The [`GenericParam`] to add.
+    #[inline]
+    pub fn push(&mut self, param: GenericParam) {
+        if matches!(param, GenericParam::Lifetime(_)) { self.0.insert(0, param) } else { self.0.push(param) }
+    }
+}
 impl ToTokens for ImplGen {
     #[inline]
     fn to_tokens(&self, tokens: &mut TokenStream2) {
@@ -278,6 +306,14 @@ impl ToTokens for TypeGen {
 
 /// Helper type for correctly serializing a list of where predicates.
 pub struct WhereClause(Punctuated<WherePredicate, Token![,]>);
+impl WhereClause {
+    /// Adds an additional predicate to the where clause.
+    ///
+    /// # Arguments
+    /// - `pred`: The [`WherePredicate`] to add.
+    #[inline]
+    pub fn push(&mut self, pred: WherePredicate) { self.0.push(pred) }
+}
 impl ToTokens for WhereClause {
     #[inline]
     fn to_tokens(&self, tokens: &mut TokenStream2) {
@@ -293,6 +329,61 @@ impl ToTokens for WhereClause {
 
 
 /***** LIBRARY FUNCTIONS *****/
+/// Parses the data-level attributes in search of answers.
+///
+/// # Generics
+/// - `T`: Some macro-specific toplevel attribute to parse anything that isn't generic-related
+///   as. Use [`NoCustomAttrs`] if the macro doesn't have any.
+///
+/// # Arguments
+/// - `base_ident`: The name of any attributes to look for (e.g., `debug`). Note that the
+///   [common attribute](COMMON_ATTR_NAME) is always included.
+/// - `attrs`: The list of [`Attribute`]s to parse.
+///
+/// # Returns
+/// A [`ToplevelAttrs`] encoding a list of params and a matching where clause that shalt be the
+/// bound for this impl (if any were given), together with a list of macro-specific attributes.
+///
+/// # Errors
+/// Note that this function can error if an attribute belonging to th(i|e)s(e) macro(s) was given,
+/// but we failed to understand it.
+pub fn parse_toplevel_attrs<T: Parse>(base_ident: &str, attrs: &[Attribute]) -> Result<(ToplevelAttrs, Vec<T>), Error> {
+    let mut impl_gen: Option<Punctuated<GenericParam, Token![,]>> = None;
+    let mut ty_gen: Option<Punctuated<GenericArgument, Token![,]>> = None;
+    let mut where_clause: Option<Punctuated<WherePredicate, Token![,]>> = None;
+    let mut custom: Vec<T> = Vec::new();
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(l) if l.path.is_ident(COMMON_ATTR_NAME) || l.path.is_ident(base_ident) => {
+                // Parse the contents of the list as a further set of metas
+                let attrs: Punctuated<ToplevelAttr<T>, Token![,]> = Attribute::parse_args_with(attr, Punctuated::parse_terminated)?;
+                for attr in attrs {
+                    match attr {
+                        ToplevelAttr::ImplGen(params) => {
+                            impl_gen = Some(params);
+                        },
+                        ToplevelAttr::TypeGen(args) => {
+                            ty_gen = Some(args);
+                        },
+                        ToplevelAttr::WhereClause(preds) => {
+                            where_clause = Some(preds);
+                        },
+                        ToplevelAttr::Custom(attr) => custom.push(attr),
+                    }
+                }
+            },
+
+            // Anything else, we ignore
+            _ => continue,
+        }
+    }
+
+    // Return appropriately
+    Ok((ToplevelAttrs { impl_gen, ty_gen, where_clause }, custom))
+}
+
+
+
 /// Collects the generics necessary for the various macro implementations.
 ///
 /// # Arguments
@@ -306,7 +397,47 @@ impl ToTokens for WhereClause {
 /// A [`Generics`] that can be used for the impl.
 pub fn extract_generics(base_ident: &str, attrs: &[Attribute], input: &DeriveInput, target: &Path) -> Result<(ImplGen, TypeGen, WhereClause), Error> {
     // parse the arguments
-    let ToplevelAttrs { impl_gen, ty_gen, where_clause } = parse_toplevel_attrs(base_ident, attrs)?;
+    let (toplevel, _) = parse_toplevel_attrs::<NoCustomAttrs>(base_ident, attrs)?;
+
+    // Simply bound all types in the struct
+    let tys: Box<dyn Iterator<Item = &Type>> = match &input.data {
+        Data::Enum(e) => Box::new(e.variants.iter().flat_map(|variant| match &variant.fields {
+            Fields::Named(n) => Box::new(n.named.iter().map(|f| &f.ty)) as Box<dyn Iterator<Item = &Type>>,
+            Fields::Unnamed(u) => Box::new(u.unnamed.iter().map(|f| &f.ty)),
+            Fields::Unit => Box::new(None::<&Type>.into_iter()),
+        })) as Box<dyn Iterator<Item = &Type>>,
+        Data::Struct(s) => Box::new(match &s.fields {
+            Fields::Named(n) => Box::new(n.named.iter().map(|f| &f.ty)) as Box<dyn Iterator<Item = &Type>>,
+            Fields::Unnamed(u) => Box::new(u.unnamed.iter().map(|f| &f.ty)),
+            Fields::Unit => Box::new(None::<&Type>.into_iter()),
+        }),
+        Data::Union(_) => todo!(),
+    };
+    Ok(extract_generics_with(&toplevel, input, target, tys.map(|ty| (ty, trait_bounds(target)))))
+}
+
+/// Collects the generics necessary for the various macro implementations, but only generates
+/// bounds for the given types.
+///
+/// This is useful for macros that don't need all fields to implement the same trait. For example,
+/// the `Default`-macro doesn't need fields with explicit values to implement `Default`.
+///
+/// # Arguments
+/// - `toplevel`: Some [`ToplevelAttrs`] parsed with [`parse_toplevel_attrs()`].
+/// - `input`: The [`DeriveInput`] which we will scan to collect the genreics.
+/// - `target`: A [`Path`] encoding the target trait to replace `r#trait` with.
+/// - `bounds`: A list of field types and the bounds to generate for them. Note that they are only
+///   generated if the type depends on any of the generics.
+///
+/// # Returns
+/// A [`Generics`] that can be used for the impl.
+pub fn extract_generics_with<'t>(
+    toplevel: &ToplevelAttrs,
+    input: &DeriveInput,
+    target: &Path,
+    bounds: impl IntoIterator<Item = (&'t Type, Punctuated<TypeParamBound, Token![+]>)>,
+) -> (ImplGen, TypeGen, WhereClause) {
+    let ToplevelAttrs { impl_gen, ty_gen, where_clause } = toplevel.clone();
 
     // Then either use the given parameters, replacing `r#trait` where needed; or copy the impl
     // ones
@@ -367,21 +498,8 @@ pub fn extract_generics(base_ident: &str, attrs: &[Attribute], input: &DeriveInp
         }
         where_clause
     } else {
-        // Simply find a list of all types in the struct
         let mut preds = Punctuated::new();
-        for ty in match &input.data {
-            Data::Enum(e) => Box::new(e.variants.iter().flat_map(|variant| match &variant.fields {
-                Fields::Named(n) => Box::new(n.named.iter().map(|f| &f.ty)) as Box<dyn Iterator<Item = &Type>>,
-                Fields::Unnamed(u) => Box::new(u.unnamed.iter().map(|f| &f.ty)),
-                Fields::Unit => Box::new(None::<&Type>.into_iter()),
-            })) as Box<dyn Iterator<Item = &Type>>,
-            Data::Struct(s) => Box::new(match &s.fields {
-                Fields::Named(n) => Box::new(n.named.iter().map(|f| &f.ty)) as Box<dyn Iterator<Item = &Type>>,
-                Fields::Unnamed(u) => Box::new(u.unnamed.iter().map(|f| &f.ty)),
-                Fields::Unit => Box::new(None::<&Type>.into_iter()),
-            }),
-            Data::Union(_) => todo!(),
-        } {
+        for (ty, bounds) in bounds {
             // Skip this type if it doesn't contain any generics (then it's not up to us to define
             // additional bounds)
             if !HasGenericsVisitor::has_generics(ty, &impl_gen) {
@@ -389,30 +507,334 @@ pub fn extract_generics(base_ident: &str, attrs: &[Attribute], input: &DeriveInp
             }
 
             // It does, so add it as a bound
-            preds.push(WherePredicate::Type(PredicateType {
-                lifetimes:   None,
-                bounded_ty:  ty.clone(),
-                colon_token: Default::default(),
-                bounds:      {
-                    let mut bounds = Punctuated::new();
-                    bounds.push(TypeParamBound::Trait(TraitBound {
-                        paren_token: Default::default(),
-                        modifier: TraitBoundModifier::None,
-                        lifetimes: None,
-                        path: target.clone(),
-                    }));
-                    bounds
-                },
-            }));
+            preds.push(WherePredicate::Type(PredicateType { lifetimes: None, bounded_ty: ty.clone(), colon_token: Default::default(), bounds }));
         }
         preds
     };
 
     // Done! Return that
-    Ok((ImplGen(impl_gen), TypeGen(ty_gen), WhereClause(where_clause)))
+    (ImplGen(impl_gen), TypeGen(ty_gen), WhereClause(where_clause))
 }
 
+/// Checks whether a type uses any of the given generics.
+///
+/// This is the same check [`extract_generics_with()`] uses to decide which types to bound.
+///
+/// # Arguments
+/// - `ty`: Some [`Type`] to check.
+/// - `generics`: The [`GenericParam`]s the given `ty`pe may use.
+///
+/// # Returns
+/// True if `ty` uses any of the type parameters in `generics`, or false otherwise.
+#[inline]
+pub fn has_generics(ty: &Type, generics: &Punctuated<GenericParam, Token![,]>) -> bool { HasGenericsVisitor::has_generics(ty, generics) }
+
+/// Checks whether a type is one of the given type parameters, on its own.
+///
+/// Such types may be anything, and can therefore not be used as the `Self` of a foreign trait
+/// impl or be told apart from other types.
+///
+/// # Arguments
+/// - `ty`: Some [`Type`] to check.
+/// - `generics`: The [`GenericParam`]s the given `ty`pe may be.
+///
+/// # Returns
+/// The [`Ident`] of the type parameter if `ty` is one, or [`None`] otherwise.
+pub fn bare_type_param<'t>(ty: &'t Type, generics: &Punctuated<GenericParam, Token![,]>) -> Option<&'t Ident> {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => {
+            let ident: &Ident = path.get_ident()?;
+            generics.iter().any(|param| matches!(param, GenericParam::Type(param) if param.ident == *ident)).then_some(ident)
+        },
+        Type::Group(TypeGroup { elem, .. }) | Type::Paren(TypeParen { elem, .. }) => bare_type_param(elem, generics),
+        _ => None,
+    }
+}
 
+/// Builds a path to something in the standard library.
+///
+/// # Arguments
+/// - `module`: The module in `std` where the thing lives.
+/// - `name`: The name of the thing.
+///
+/// # Returns
+/// A [`Path`] referring to `::std::<module>::<name>`.
+pub fn std_path(module: &str, name: &str) -> Path {
+    Path {
+        leading_colon: Some(Default::default()),
+        segments:      {
+            let mut segments = Punctuated::new();
+            segments.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new(module, Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new(name, Span::call_site()), arguments: PathArguments::None });
+            segments
+        },
+    }
+}
+
+/// Adds generic arguments to the last segment of a path.
+///
+/// # Arguments
+/// - `path`: The [`Path`] to add arguments to (e.g., one built with [`std_path()`]).
+/// - `args`: The [`GenericArgument`]s to add.
+///
+/// # Returns
+/// The given `path`, but with the arguments added (e.g., `::std::convert::AsRef<str>`).
+pub fn path_with_args(mut path: Path, args: impl IntoIterator<Item = GenericArgument>) -> Path {
+    path.segments.last_mut().unwrap().arguments = PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+        colon2_token: None,
+        lt_token:     Default::default(),
+        args:         args.into_iter().collect(),
+        gt_token:     Default::default(),
+    });
+    path
+}
+
+/// Generates the member that refers to a particular field.
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] the field is a part of.
+/// - `i`: The index of the field.
+///
+/// # Returns
+/// A [`TokenStream2`] with either the field's identifier or its index.
+pub fn member(fields: &Fields, i: usize) -> TokenStream2 {
+    let f: &Field = fields.iter().nth(i).unwrap();
+    f.ident.as_ref().map(Ident::to_token_stream).unwrap_or_else(|| Index { index: i as u32, span: f.span() }.to_token_stream())
+}
+
+/// Builds a list of bounds that only contains the given trait.
+///
+/// # Arguments
+/// - `target`: A [`Path`] encoding the trait to bound on.
+///
+/// # Returns
+/// A list of [`TypeParamBound`]s that can be given to [`extract_generics_with()`].
+pub fn trait_bounds(target: &Path) -> Punctuated<TypeParamBound, Token![+]> {
+    let mut bounds = Punctuated::new();
+    bounds.push(TypeParamBound::Trait(TraitBound {
+        paren_token: Default::default(),
+        modifier: TraitBoundModifier::None,
+        lifetimes: None,
+        path: target.clone(),
+    }));
+    bounds
+}
+
+/// Converts a `PascalCase` name (e.g., of a variant) to `snake_case`.
+///
+/// Every uppercase letter is assumed to start a new word, except within runs of them, which are
+/// kept together as acronyms (e.g., `HTTPServer` becomes `http_server`).
+///
+/// # Arguments
+/// - `name`: The name to convert.
+///
+/// # Returns
+/// The converted name.
+pub fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut res = String::with_capacity(name.len() + name.len() / 2);
+    for (i, c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            // Start a new word after a lowercase letter or digit, or when this is the last letter
+            // of an acronym that starts the next word (e.g., the `S` in `HTTPServer`)
+            let prev: char = chars[i - 1];
+            let next_lower: bool = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if !prev.is_uppercase() || next_lower {
+                res.push('_');
+            }
+        }
+        res.extend(c.to_lowercase());
+    }
+    res
+}
+
+
+
+/// Finds the field to forward an impl to.
+///
+/// This is either the only field of a struct, or the one marked with `#[marker]`.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to find the field in.
+/// - `marker`: The name of the attribute marking the field (e.g., `deref` for `#[deref]`).
+/// - `trait_name`: The name of the trait for which the impl is generated. Only used for errors.
+///
+/// # Returns
+/// The member referring to the field, and the field itself.
+///
+/// # Errors
+/// This function fails if the input is not a struct, or if there is no or more than one eligible
+/// field.
+pub fn find_marked_field<'i>(input: &'i DeriveInput, marker: &str, trait_name: &str) -> Result<(TokenStream2, &'i Field), Error> {
+    let s = match &input.data {
+        Data::Struct(s) => s,
+        Data::Enum(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), format!("The `{trait_name}` derive macro can only be used on structs"))),
+    };
+
+    // Find any marked field
+    let mut marked: Option<(usize, &'i Field)> = None;
+    for (i, f) in s.fields.iter().enumerate() {
+        for attr in &f.attrs {
+            match &attr.meta {
+                Meta::Path(p) if p.is_ident(marker) => {
+                    if marked.is_some() {
+                        return Err(Error::new(p.span(), format!("Only one field can be marked as `#[{marker}]`")));
+                    }
+                    marked = Some((i, f));
+                },
+                Meta::List(l) if l.path.is_ident(marker) => {
+                    return Err(Error::new(l.span(), format!("`#[{marker}]` does not accept any arguments")));
+                },
+
+                // Anything else, we ignore
+                _ => continue,
+            }
+        }
+    }
+
+    // If there was none, fall back to the only field
+    let (i, f): (usize, &'i Field) = match marked {
+        Some(res) => res,
+        None if s.fields.len() == 1 => (0, s.fields.iter().next().unwrap()),
+        None if s.fields.is_empty() => {
+            return Err(Error::new(input.ident.span(), format!("Cannot derive `{trait_name}` for a struct without fields")));
+        },
+        None => {
+            return Err(Error::new(
+                input.ident.span(),
+                format!("Cannot derive `{trait_name}` for a struct with multiple fields (mark the field to forward to with `#[{marker}]`)"),
+            ));
+        },
+    };
+    Ok((member(&s.fields, i), f))
+}
+
+/// Collects the variants of an enum that have precisely one field.
+///
+/// This is used by macros that generate one impl per variant, based on the type of its field.
+/// Variants marked with `#[SOME_IDENT(skip)]` are ignored.
+///
+/// # Arguments
+/// - `base_ident`: The initialization of `SOME_IDENT`. Note that the
+///   [common attribute](COMMON_ATTR_NAME) is always included.
+/// - `trait_name`: The name of the trait for which the impls are generated. Only used for errors.
+/// - `variants`: The list of [`Variant`]s to collect from.
+/// - `generics`: The [`GenericParam`]s of the enum, which the fields' types may use.
+///
+/// # Returns
+/// A list of the found [`Variant`]s together with their single [`Field`].
+///
+/// # Errors
+/// This function fails if we failed to parse the skip-attributes, or if two variants have the same
+/// field type or one has a bare type parameter that may be the other's (which would both generate
+/// conflicting impls).
+pub fn single_field_variants<'v>(
+    base_ident: &str,
+    trait_name: &str,
+    variants: &'v Punctuated<Variant, Token![,]>,
+    generics: &Punctuated<GenericParam, Token![,]>,
+) -> Result<Vec<(&'v Variant, &'v Field)>, Error> {
+    let mut res: Vec<(&'v Variant, &'v Field)> = Vec::with_capacity(variants.len());
+    let mut seen: Vec<String> = Vec::with_capacity(variants.len());
+    for variant in variants {
+        // Only consider non-skipped variants with precisely one field
+        if parse_field_attrs(base_ident, &variant.attrs)? || variant.fields.len() != 1 {
+            continue;
+        }
+        let f: &Field = variant.fields.iter().next().unwrap();
+
+        // Assert we didn't have this type before
+        // NOTE: We compare the types syntactically, which is the best we can do here
+        let ty_str: String = f.ty.to_token_stream().to_string();
+        if let Some(i) = seen.iter().position(|ty| *ty == ty_str) {
+            return Err(Error::new(
+                f.ty.span(),
+                format!(
+                    "Variant `{}` would generate a `{trait_name}`-impl that conflicts with the one of variant `{}` (use `#[{base_ident}(skip)]` on either \
+                     to resolve)",
+                    variant.ident, res[i].0.ident
+                ),
+            ));
+        }
+        seen.push(ty_str);
+        res.push((variant, f));
+    }
+
+    // Assert that bare type parameters can't be any of the other types
+    let tys: Vec<&Type> = res.iter().map(|(_, f)| &f.ty).collect();
+    if let Some((i, j, param)) = find_bare_type_param_conflict(&tys, generics) {
+        return Err(Error::new(
+            tys[i].span(),
+            format!(
+                "Variant `{}` would generate a `{trait_name}`-impl that conflicts with the one of variant `{}`, as its field is the bare type \
+                 parameter `{param}` (use `#[{base_ident}(skip)]` on either to resolve)",
+                res[i].0.ident, res[j].0.ident
+            ),
+        ));
+    }
+    Ok(res)
+}
+
+/// Finds two types of which the first is a bare type parameter that may be the second.
+///
+/// This is used by macros that generate one impl per type, which would then conflict. Types using
+/// the same parameter are fine, as it can't be those (e.g., `T` vs. `Vec<T>`).
+///
+/// # Arguments
+/// - `tys`: The list of [`Type`]s to check.
+/// - `generics`: The [`GenericParam`]s the given `tys` may use.
+///
+/// # Returns
+/// The indices of the bare type parameter and the type it may be, together with the parameter's
+/// [`Ident`], or [`None`] if there is no such pair.
+pub fn find_bare_type_param_conflict<'t>(
+    tys: &[&'t Type],
+    generics: &Punctuated<GenericParam, Token![,]>,
+) -> Option<(usize, usize, &'t Ident)> {
+    for (i, ty) in tys.iter().enumerate() {
+        let Some(param) = bare_type_param(ty, generics) else { continue };
+        let param_generics: Punctuated<GenericParam, Token![,]> = generics
+            .iter()
+            .filter(|gen_param| matches!(gen_param, GenericParam::Type(gen_param) if gen_param.ident == *param))
+            .cloned()
+            .collect();
+        if let Some(j) = (0..tys.len()).find(|j| *j != i && !has_generics(tys[*j], &param_generics)) {
+            return Some((i, j, param));
+        }
+    }
+    None
+}
+
+/// Computes the discriminants of all variants of an enum.
+///
+/// Variants without an explicit discriminant get the one of the previous variant plus one, or `0`
+/// if they are the first.
+///
+/// # Arguments
+/// - `variants`: The list of [`Variant`]s to compute the discriminants of.
+///
+/// # Returns
+/// A list with the discriminant of every variant, in order.
+///
+/// # Errors
+/// This function fails if any explicit discriminant is not a (possibly negative) integer literal.
+pub fn parse_discriminants(variants: &Punctuated<Variant, Token![,]>) -> Result<Vec<i128>, Error> {
+    let mut discriminants: Vec<i128> = Vec::with_capacity(variants.len());
+    for variant in variants {
+        let discriminant: i128 = match &variant.discriminant {
+            Some((_, Expr::Lit(ExprLit { lit: Lit::Int(i), .. }))) => i.base10_parse()?,
+            Some((_, Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. }))) => match &**expr {
+                Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => -i.base10_parse::<i128>()?,
+                expr => return Err(Error::new(expr.span(), "Expected an integer literal")),
+            },
+            Some((_, expr)) => return Err(Error::new(expr.span(), "Expected an integer literal")),
+            None => discriminants.last().map(|d| *d + 1).unwrap_or(0),
+        };
+        discriminants.push(discriminant);
+    }
+    Ok(discriminants)
+}
 
 /// Filters an existing `Data` to skip any variants and fields with a given
 /// `#[SOME_IDENT(skip)]`-attribute.
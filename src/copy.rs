@@ -38,9 +38,9 @@ pub fn copy(input: TokenStream) -> TokenStream {
         leading_colon: Some(Default::default()),
         segments:      {
             let mut segments = Punctuated::new();
-            segments.push(PathSegment { ident: Ident::new("std".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("marker".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("Copy".into(), Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("marker", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("Copy", Span::call_site()), arguments: PathArguments::None });
             segments
         },
     }) {
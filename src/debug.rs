@@ -160,9 +160,9 @@ pub fn debug(input: TokenStream) -> TokenStream {
         leading_colon: Some(Default::default()),
         segments:      {
             let mut segments = Punctuated::new();
-            segments.push(PathSegment { ident: Ident::new("std".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("fmt".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("Debug".into(), Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("fmt", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("Debug", Span::call_site()), arguments: PathArguments::None });
             segments
         },
     }) {
@@ -0,0 +1,245 @@
+//  DEFAULT.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 10:12:41
+//  Last edited:
+//    19 Oct 2026, 09:31:05
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Default`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::{Attribute, Data, DeriveInput, Error, Field, Fields, Ident, Meta, Path, Token, Type, Variant, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, parse_toplevel_attrs, std_path, trait_bounds};
+
+
+/***** HELPERS *****/
+/// Defines how to initialize a particular field.
+enum FieldInit {
+    /// Use the field type's `Default`-impl.
+    Default,
+    /// Use the given expression.
+    Expr(TokenStream2),
+    /// Call the given function.
+    With(Path),
+}
+impl Parse for FieldInit {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Check if it's a `with = PATH`
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let ident: Ident = input.fork().parse()?;
+            if ident == "with" {
+                input.parse::<Ident>()?;
+                input.parse::<Token![=]>()?;
+                return Ok(Self::With(input.parse()?));
+            }
+        }
+
+        // Otherwise, it's an arbitrary expression
+        Ok(Self::Expr(input.parse()?))
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Parses `#[default(...)]` on field attributes.
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to parse.
+///
+/// # Returns
+/// A [`FieldInit`] describing how to initialize the field.
+///
+/// # Errors
+/// This function fails if the attribute was given without a value or more than once, or if we failed
+/// to parse it.
+fn parse_field_attrs(attrs: &[Attribute]) -> Result<FieldInit, Error> {
+    let mut init: FieldInit = FieldInit::Default;
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(l) if l.path.is_ident("default") => {
+                if !matches!(init, FieldInit::Default) {
+                    return Err(Error::new(l.span(), "A field can only be given one value with `#[default(...)]`"));
+                }
+                init = attr.parse_args()?;
+            },
+            Meta::Path(p) if p.is_ident("default") => {
+                return Err(Error::new(
+                    p.span(),
+                    "Expected a value for the field (e.g., `#[default(42)]` or `#[default(with = Vec::new)]`)",
+                ));
+            },
+
+            // Anything else, we ignore
+            _ => continue,
+        }
+    }
+    Ok(init)
+}
+
+/// Finds the variant marked with `#[default]`.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] that we're deriving for.
+/// - `variants`: The list of [`Variant`]s to search.
+///
+/// # Returns
+/// The [`Variant`] marked as default.
+///
+/// # Errors
+/// This function fails if there is no, or more than one, variant marked with `#[default]`.
+fn find_default_variant<'v>(input: &DeriveInput, variants: &'v Punctuated<Variant, Token![,]>) -> Result<&'v Variant, Error> {
+    let mut default: Option<&'v Variant> = None;
+    for variant in variants {
+        for attr in &variant.attrs {
+            match &attr.meta {
+                Meta::Path(p) if p.is_ident("default") => {
+                    if default.is_some() {
+                        return Err(Error::new(p.span(), "Only one variant can be marked as `#[default]`"));
+                    }
+                    default = Some(variant);
+                },
+                Meta::List(l) if l.path.is_ident("default") => {
+                    return Err(Error::new(l.span(), "Variants can only be marked as `#[default]`, without any arguments"));
+                },
+
+                // Anything else, we ignore
+                _ => continue,
+            }
+        }
+    }
+    default.ok_or_else(|| Error::new(input.ident.span(), "Expected one variant to be marked as `#[default]`"))
+}
+
+/// Given a list of fields, builds the initializers for it and collects the types that need to
+/// implement `Default` for that.
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] to generate initializers for.
+///
+/// # Returns
+/// A tuple with a list of initializers for every field and a list of the field types that
+/// need to implement `Default`.
+///
+/// # Errors
+/// This function fails if we failed to parse the field's attributes.
+fn generate_field_inits(fields: &Punctuated<Field, Token![,]>) -> Result<(Vec<TokenStream2>, Vec<&Type>), Error> {
+    let mut inits: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    let mut tys: Vec<&Type> = Vec::with_capacity(fields.len());
+    for f in fields {
+        let ty: &Type = &f.ty;
+        let init: TokenStream2 = match parse_field_attrs(&f.attrs)? {
+            FieldInit::Default => {
+                tys.push(ty);
+                quote! { <#ty as ::std::default::Default>::default() }
+            },
+            FieldInit::Expr(expr) => quote! { #expr },
+            FieldInit::With(path) => quote! { #path() },
+        };
+        inits.push(match &f.ident {
+            Some(ident) => quote! { #ident: #init },
+            None => init,
+        });
+    }
+    Ok((inits, tys))
+}
+
+/// Builds the necessary default implementation.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] which we will scan to collect the genreics.
+///
+/// # Returns
+/// A [`TokenStream2`] that can be used for the impl and a list of types that need to implement
+/// `Default` for it.
+///
+/// # Errors
+/// This function fails if we failed to parse any of the attributes, or if an enum has no default
+/// variant.
+fn build_default_impl(input: &DeriveInput) -> Result<(TokenStream2, Vec<&Type>), Error> {
+    // Match based on the data type
+    match &input.data {
+        Data::Enum(e) => {
+            let variant: &Variant = find_default_variant(input, &e.variants)?;
+            let variant_name = &variant.ident;
+            match &variant.fields {
+                Fields::Named(n) => {
+                    let (inits, tys) = generate_field_inits(&n.named)?;
+                    Ok((quote! { Self::#variant_name { #(#inits),* } }, tys))
+                },
+                Fields::Unnamed(u) => {
+                    let (inits, tys) = generate_field_inits(&u.unnamed)?;
+                    Ok((quote! { Self::#variant_name(#(#inits),*) }, tys))
+                },
+                Fields::Unit => Ok((quote! { Self::#variant_name }, Vec::new())),
+            }
+        },
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(n) => {
+                let (inits, tys) = generate_field_inits(&n.named)?;
+                Ok((quote! { Self { #(#inits),* } }, tys))
+            },
+            Fields::Unnamed(u) => {
+                let (inits, tys) = generate_field_inits(&u.unnamed)?;
+                Ok((quote! { Self(#(#inits),*) }, tys))
+            },
+            Fields::Unit => Ok((quote! { Self }, Vec::new())),
+        },
+        Data::Union(_) => Err(Error::new(input.ident.span(), "The `Default` derive macro can only be used on structs and enums")),
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Default` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn default(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Build the body first, as that tells us which fields need to be bound
+    let (default, tys) = match build_default_impl(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the general impl
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("default", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("default", "Default");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, tys.into_iter().map(|ty| (ty, trait_bounds(&target))));
+
+    // Done, build the impl
+    let name = &input.ident;
+    quote! {
+        impl #impl_gen ::std::default::Default for #name #ty_gen #where_clause {
+            #[inline]
+            fn default() -> Self {
+                #default
+            }
+        }
+    }
+    .into()
+}
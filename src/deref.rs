@@ -0,0 +1,95 @@
+//  DEREF.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 18:02:19
+//  Last edited:
+//    17 Oct 2026, 18:24:53
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Deref`- and `DerefMut`-macros.
+//
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Path, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, find_marked_field, parse_toplevel_attrs, std_path};
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Deref` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn deref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Find the field to forward to
+    let (member, field) = match find_marked_field(&input, "deref", "Deref") {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the impl. No need to bound anything, as we only forward.
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("deref", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("ops", "Deref");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // Done, build the impl
+    let name = &input.ident;
+    let ty = &field.ty;
+    quote! {
+        impl #impl_gen ::std::ops::Deref for #name #ty_gen #where_clause {
+            type Target = #ty;
+
+            #[inline]
+            fn deref(&self) -> &#ty { &self.#member }
+        }
+    }
+    .into()
+}
+
+/// Actual implementation of the `DerefMut` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn deref_mut(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Find the field to forward to
+    let (member, field) = match find_marked_field(&input, "deref", "DerefMut") {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the impl. No need to bound anything, as we only forward.
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("deref_mut", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("ops", "DerefMut");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // Done, build the impl
+    let name = &input.ident;
+    let ty = &field.ty;
+    quote! {
+        impl #impl_gen ::std::ops::DerefMut for #name #ty_gen #where_clause {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut #ty { &mut self.#member }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,271 @@
+//  DIFF.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 05:41:22
+//  Last edited:
+//    19 Oct 2026, 10:32:18
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Diff`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt as _;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Data, DeriveInput, Error, Fields, GenericParam, Ident, Path, Token, Type, TypeParamBound, TypePath, parse_macro_input};
+
+use crate::common::{
+    NoCustomAttrs, extract_generics_with, has_generics, parse_field_attrs, parse_field_attrs_with, parse_toplevel_attrs, std_path, trait_bounds,
+};
+
+
+/***** HELPERS *****/
+/// Defines the attributes of the `Diff`-macro on fields.
+enum DiffAttr {
+    /// The field's type derives `Diff` too, and should be recursed into.
+    Nested,
+}
+impl Parse for DiffAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "nested" {
+            Ok(Self::Nested)
+        } else {
+            Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+
+
+/// Defines the code generated for a list of fields.
+struct FieldsDiff<'f> {
+    /// The pattern binding the fields of the left value.
+    lpattern: TokenStream2,
+    /// The pattern binding the fields of the right value.
+    rpattern: TokenStream2,
+    /// The statements comparing the bound fields.
+    diffs:    Vec<TokenStream2>,
+    /// The types of the fields that are compared directly, and thus need bounds.
+    tys:      Vec<&'f Type>,
+    /// The types of the fields that are recursed into.
+    nested:   Vec<&'f Type>,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Given a list of fields, builds the patterns binding the left- and right fields and the
+/// statements comparing them.
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] to generate the comparisons for.
+/// - `path`: The path to the struct or variant that has these fields.
+///
+/// # Returns
+/// A [`FieldsDiff`] describing the generated code.
+///
+/// # Errors
+/// This function fails if we failed to parse the fields' attributes.
+fn generate_field_diffs(fields: &Fields, path: TokenStream2) -> Result<FieldsDiff<'_>, Error> {
+    let mut ls: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    let mut rs: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    let mut diffs: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    let mut tys: Vec<&Type> = Vec::with_capacity(fields.len());
+    let mut nested_tys: Vec<&Type> = Vec::new();
+    for (i, f) in fields.iter().enumerate() {
+        // Parse the attributes, which may ignore the field
+        let (skip, attrs) = parse_field_attrs_with::<DiffAttr>("diff", &f.attrs)?;
+        let skip: bool = skip || parse_field_attrs("partial_eq", &f.attrs)?;
+        let nested: bool = attrs.iter().any(|attr| matches!(attr, DiffAttr::Nested));
+
+        // Generate the left- and right bindings
+        let (lident, rident): (Ident, Ident) = (format_ident!("__l{}", i), format_ident!("__r{}", i));
+        let (lbinding, rbinding): (TokenStream2, TokenStream2) = if skip { (quote! { _ }, quote! { _ }) } else { (quote! { #lident }, quote! { #rident }) };
+        let name: String = match &f.ident {
+            Some(ident) => {
+                ls.push(quote! { #ident: #lbinding });
+                rs.push(quote! { #ident: #rbinding });
+                ident.unraw().to_string()
+            },
+            None => {
+                ls.push(lbinding);
+                rs.push(rbinding);
+                i.to_string()
+            },
+        };
+        if skip {
+            continue;
+        }
+
+        // Generate the comparison
+        let ty: &Type = &f.ty;
+        diffs.push(if nested {
+            nested_tys.push(ty);
+            quote! { <#ty>::__diff_into(#lident, #rident, &__join(#name), __diffs); }
+        } else {
+            tys.push(ty);
+            quote! {
+                if !<#ty as ::std::cmp::PartialEq>::eq(#lident, #rident) {
+                    __diffs.push((__join(#name), #lident, #rident));
+                }
+            }
+        });
+    }
+
+    // Build the patterns
+    let (lpattern, rpattern): (TokenStream2, TokenStream2) = match fields {
+        Fields::Named(_) => (quote! { #path { #(#ls),* } }, quote! { #path { #(#rs),* } }),
+        Fields::Unnamed(_) => (quote! { #path(#(#ls),*) }, quote! { #path(#(#rs),*) }),
+        Fields::Unit => (path.clone(), path),
+    };
+    Ok(FieldsDiff { lpattern, rpattern, diffs, tys, nested: nested_tys })
+}
+
+/// Builds the body of the `__diff_into()`-implementation.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to build the implementation for.
+///
+/// # Returns
+/// The body of the implementation, the types of the fields that need to be bound and the types of
+/// the nested fields.
+///
+/// # Errors
+/// This function fails if we failed to parse the fields' attributes.
+#[allow(clippy::type_complexity)]
+fn build_diff_impl(input: &DeriveInput) -> Result<(TokenStream2, Vec<&Type>, Vec<&Type>), Error> {
+    match &input.data {
+        Data::Enum(e) => {
+            // Build the arms for every variant
+            let mut arms: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
+            let mut names: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
+            let mut tys: Vec<&Type> = Vec::new();
+            let mut nested: Vec<&Type> = Vec::new();
+            for variant in &e.variants {
+                let variant_name = &variant.ident;
+                let svariant_name = variant_name.to_string();
+                let FieldsDiff { lpattern, rpattern, diffs, tys: vtys, nested: vnested } =
+                    generate_field_diffs(&variant.fields, quote! { Self::#variant_name })?;
+                arms.push(quote! { (#lpattern, #rpattern) => { #(#diffs)* }, });
+                names.push(quote! { Self::#variant_name { .. } => &__VariantName(#svariant_name), });
+                tys.extend(vtys);
+                nested.extend(vnested);
+            }
+
+            // If the variants differ, we report their names
+            let body: TokenStream2 = quote! {
+                match (self, __other) {
+                    #(#arms)*
+                    #[allow(unreachable_patterns)]
+                    (__l, __r) => {
+                        // NOTE: Wrapped so that the names are printed without quotes
+                        struct __VariantName(&'static str);
+                        impl ::std::fmt::Debug for __VariantName {
+                            #[inline]
+                            fn fmt(&self, __f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result { __f.write_str(self.0) }
+                        }
+                        let __name = |__value: &Self| -> &'static dyn ::std::fmt::Debug {
+                            match *__value {
+                                #(#names)*
+                            }
+                        };
+                        __diffs.push((::std::borrow::ToOwned::to_owned(__path), __name(__l), __name(__r)));
+                    },
+                }
+            };
+            Ok((body, tys, nested))
+        },
+        Data::Struct(s) => {
+            let FieldsDiff { lpattern, rpattern, diffs, tys, nested } = generate_field_diffs(&s.fields, quote! { Self })?;
+            Ok((quote! { let (#lpattern, #rpattern) = (self, __other); #(#diffs)* }, tys, nested))
+        },
+        Data::Union(_) => Err(Error::new(input.ident.span(), "The `Diff` derive macro can only be used on structs and enums")),
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Diff` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn diff(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Build the body first
+    let (body, tys, nested) = match build_diff_impl(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("diff", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Fields that are compared directly must be both comparable and printable. We can't name the
+    // bounds of the `__diff_into()` of nested fields, so instead we assume they compare fields using
+    // the type parameters we give them directly.
+    let target: Path = std_path("cmp", "PartialEq");
+    let mut bounds: Punctuated<TypeParamBound, Token![+]> = trait_bounds(&target);
+    bounds.extend(trait_bounds(&std_path("fmt", "Debug")));
+    let params: Vec<Type> = input
+        .generics
+        .type_params()
+        .filter(|param| {
+            let param: Punctuated<GenericParam, Token![,]> = Punctuated::from_iter([GenericParam::Type((*param).clone())]);
+            nested.iter().any(|ty| has_generics(ty, &param))
+        })
+        .map(|param| Type::Path(TypePath { qself: None, path: param.ident.clone().into() }))
+        .collect();
+    let (impl_gen, ty_gen, where_clause) =
+        extract_generics_with(&toplevel, &input, &target, tys.into_iter().chain(params.iter()).map(|ty| (ty, bounds.clone())));
+
+    // Done, build the impl
+    let name = &input.ident;
+    quote! {
+        impl #impl_gen #name #ty_gen #where_clause {
+            /// Returns the fields in which this value differs from another.
+            ///
+            /// Every difference is given as the path to the field (e.g., `inner.0`), and the value of
+            /// that field in this and the other value, respectively.
+            #[inline]
+            pub fn diff<'__a>(
+                &'__a self,
+                __other: &'__a Self,
+            ) -> ::std::vec::Vec<(::std::string::String, &'__a dyn ::std::fmt::Debug, &'__a dyn ::std::fmt::Debug)> {
+                let mut __diffs = ::std::vec::Vec::new();
+                self.__diff_into(__other, "", &mut __diffs);
+                __diffs
+            }
+
+            #[doc(hidden)]
+            pub fn __diff_into<'__a>(
+                &'__a self,
+                __other: &'__a Self,
+                __path: &str,
+                __diffs: &mut ::std::vec::Vec<(::std::string::String, &'__a dyn ::std::fmt::Debug, &'__a dyn ::std::fmt::Debug)>,
+            ) {
+                let __join = |__name: &str| -> ::std::string::String {
+                    if __path.is_empty() { ::std::borrow::ToOwned::to_owned(__name) } else { ::std::format!("{}.{}", __path, __name) }
+                };
+                #body
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,183 @@
+//  DISCRIMINANT.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 17:12:40
+//  Last edited:
+//    19 Oct 2026, 09:45:02
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Discriminant`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, LitInt, Meta, Path, Token, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, parse_discriminants, parse_toplevel_attrs, std_path};
+
+
+/***** CONSTANTS *****/
+/// The integer types that can be given in a `#[repr(...)]`.
+const REPR_TYPES: [&str; 12] = ["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Finds the integer type given in `#[repr(...)]`.
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to search.
+///
+/// # Returns
+/// The [`Ident`] of the integer type, or `isize` if there is none (as Rust does).
+///
+/// # Errors
+/// This function fails if we failed to parse a `#[repr(...)]`-attribute.
+fn parse_repr(attrs: &[Attribute]) -> Result<Ident, Error> {
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(l) if l.path.is_ident("repr") => {
+                for ident in attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)? {
+                    if REPR_TYPES.iter().any(|ty| ident == ty) {
+                        return Ok(ident);
+                    }
+                }
+            },
+
+            // Anything else, we ignore
+            _ => continue,
+        }
+    }
+    Ok(Ident::new("isize", Span::call_site()))
+}
+
+/// Builds the arms of the `from_discriminant()`-implementation.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to build the impl for.
+///
+/// # Returns
+/// A list of match arms mapping integers to variants.
+///
+/// # Errors
+/// This function fails if the input is not an enum, if any of its variants has fields, or if any
+/// of the discriminants is not an integer literal.
+fn build_from_discriminant_impl(input: &DeriveInput) -> Result<Vec<TokenStream2>, Error> {
+    let e = match &input.data {
+        Data::Enum(e) => e,
+        Data::Struct(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), "The `Discriminant` derive macro can only be used on enums")),
+    };
+
+    // Build the arms for every variant
+    let mut arms: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
+    for (variant, discriminant) in e.variants.iter().zip(parse_discriminants(&e.variants)?) {
+        let variant_name = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => {},
+            Fields::Named(_) | Fields::Unnamed(_) => {
+                return Err(Error::new(variant.fields.span(), "The `Discriminant` derive macro can only be used on enums without fields"));
+            },
+        }
+
+        // NOTE: Negative literals are two tokens, so we write the sign separately
+        let lit = LitInt::new(&discriminant.unsigned_abs().to_string(), variant_name.span());
+        let pattern: TokenStream2 = if discriminant < 0 { quote! { -#lit } } else { quote! { #lit } };
+        arms.push(quote! { #pattern => ::std::option::Option::Some(Self::#variant_name), });
+    }
+    Ok(arms)
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Discriminant` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn discriminant(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Build the body first
+    let arms = match build_from_discriminant_impl(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let repr = match parse_repr(&input.attrs) {
+        Ok(repr) => repr,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the impls. No need to bound anything, as there are no fields.
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("discriminant", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("convert", "TryFrom");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // Prepare the error type
+    let vis = &input.vis;
+    let name = &input.ident;
+    let sname = name.to_string();
+    let error_name = format_ident!("Invalid{}Error", name);
+    let error_doc = format!("Error returned when failing to convert an integer to a [`{sname}`].");
+
+    // Done, build the impls
+    quote! {
+        #[doc = #error_doc]
+        #[derive(::std::clone::Clone, ::std::marker::Copy, ::std::fmt::Debug, ::std::cmp::Eq, ::std::cmp::PartialEq)]
+        #vis struct #error_name {
+            /// The integer that isn't a discriminant of any variant.
+            pub raw: #repr,
+        }
+        impl ::std::fmt::Display for #error_name {
+            #[inline]
+            fn fmt(&self, __f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::write!(__f, ::std::concat!("No variant of ", #sname, " has discriminant {}"), self.raw)
+            }
+        }
+        impl ::std::error::Error for #error_name {}
+
+        impl #impl_gen #name #ty_gen #where_clause {
+            /// Returns the variant with the given discriminant, if any.
+            #[inline]
+            pub const fn from_discriminant(__value: #repr) -> ::std::option::Option<Self> {
+                match __value {
+                    #(#arms)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+
+        impl #impl_gen ::std::convert::TryFrom<#repr> for #name #ty_gen #where_clause {
+            type Error = #error_name;
+
+            #[inline]
+            fn try_from(__value: #repr) -> ::std::result::Result<Self, #error_name> {
+                match Self::from_discriminant(__value) {
+                    ::std::option::Option::Some(__value) => ::std::result::Result::Ok(__value),
+                    ::std::option::Option::None => ::std::result::Result::Err(#error_name { raw: __value }),
+                }
+            }
+        }
+
+        impl #impl_gen ::std::convert::From<#name #ty_gen> for #repr #where_clause {
+            #[inline]
+            fn from(__value: #name #ty_gen) -> Self { __value as #repr }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,545 @@
+//  DISPLAY.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 11:24:08
+//  Last edited:
+//    19 Oct 2026, 09:14:22
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Display`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::ext::IdentExt as _;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Expr, ExprLit, Field, Fields, Ident, Lit, LitStr, Meta, Token, Type, TypeParamBound, parse_macro_input,
+};
+
+use crate::common::{extract_generics_with, member, parse_toplevel_attrs, std_path, trait_bounds};
+
+
+/***** HELPERS *****/
+/// Defines the toplevel attributes of the `Display`-macro.
+enum DisplayAttr {
+    /// The format string to use.
+    Format(LitStr),
+    /// Use doc comments as format strings.
+    Doc,
+}
+impl Parse for DisplayAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            return Ok(Self::Format(input.parse()?));
+        }
+        let ident: Ident = input.parse()?;
+        if ident == "doc" {
+            Ok(Self::Doc)
+        } else {
+            Err(input.error(format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+
+
+/// Defines a parsed format string, ready to be given to `write!()`.
+struct Format {
+    /// The rewritten format string, where every argument refers to a field by binding name.
+    fmt: LitStr,
+    /// The fields used by the format string, as an index into the list of fields together with
+    /// the formatting traits they are used with.
+    uses: Vec<(usize, Vec<&'static str>)>,
+}
+impl Format {
+    /// Parses a format string in the context of the given fields.
+    ///
+    /// # Arguments
+    /// - `lit`: The [`LitStr`] that contains the format string.
+    /// - `fields`: The [`Fields`] that the format string may refer to.
+    ///
+    /// # Returns
+    /// A new Format that has all field references resolved.
+    ///
+    /// # Errors
+    /// This function fails if the format string was malformed or referred to unknown fields.
+    fn parse(lit: &LitStr, fields: &Fields) -> Result<Self, Error> {
+        let value: String = lit.value();
+        let mut fmt: String = String::with_capacity(value.len());
+        let mut uses: Vec<(usize, Vec<&'static str>)> = Vec::new();
+        let mut next_implicit: usize = 0;
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    fmt.push_str("{{");
+                },
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    fmt.push_str("}}");
+                },
+                '}' => return Err(Error::new(lit.span(), "Unmatched '}' in format string (use '}}' to escape it)")),
+                '{' => {
+                    // Collect everything up to the closing brace
+                    let mut arg: String = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => arg.push(c),
+                            None => return Err(Error::new(lit.span(), "Unmatched '{' in format string (use '{{' to escape it)")),
+                        }
+                    }
+                    let (name, spec): (&str, &str) = arg.split_once(':').unwrap_or((&arg, ""));
+
+                    // Resolve the argument itself
+                    let name: &str = name.trim();
+                    let field: usize = if name.is_empty() {
+                        next_implicit += 1;
+                        resolve_field(lit, fields, &(next_implicit - 1).to_string())?
+                    } else {
+                        resolve_field(lit, fields, name)?
+                    };
+
+                    // Then parse the spec to find the trait and any width/precision arguments
+                    let (spec, trt, args): (String, &'static str, Vec<usize>) = parse_spec(lit, fields, spec)?;
+                    for arg in args {
+                        add_use(&mut uses, arg, None);
+                    }
+                    add_use(&mut uses, field, Some(trt));
+                    fmt.push('{');
+                    fmt.push_str(&binding_name(fields, field));
+                    if !spec.is_empty() {
+                        fmt.push(':');
+                        fmt.push_str(&spec);
+                    }
+                    fmt.push('}');
+                },
+                c => fmt.push(c),
+            }
+        }
+        Ok(Self { fmt: LitStr::new(&fmt, lit.span()), uses })
+    }
+
+    /// Generates the pattern that binds the used fields.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the struct or variant to match (e.g., `Self` or `Self::Variant`).
+    /// - `fields`: The [`Fields`] that the format string was parsed with.
+    ///
+    /// # Returns
+    /// A [`TokenStream2`] encoding the pattern.
+    fn pattern(&self, path: TokenStream2, fields: &Fields) -> TokenStream2 {
+        let members: Vec<TokenStream2> = self.uses.iter().map(|(i, _)| member(fields, *i)).collect();
+        let bindings: Vec<Ident> = self.uses.iter().map(|(i, _)| binding_ident(fields, *i)).collect();
+        quote! { #path { #(#members: #bindings,)* .. } }
+    }
+
+    /// Generates the `write!()`-call that writes the format string.
+    ///
+    /// # Arguments
+    /// - `fields`: The [`Fields`] that the format string was parsed with.
+    ///
+    /// # Returns
+    /// A [`TokenStream2`] encoding the call.
+    fn write(&self, fields: &Fields) -> TokenStream2 {
+        let fmt: &LitStr = &self.fmt;
+        let bindings: Vec<Ident> = self.uses.iter().map(|(i, _)| binding_ident(fields, *i)).collect();
+        quote! { ::std::write!(__f, #fmt, #(#bindings = #bindings),*) }
+    }
+
+    /// Collects the bounds for the fields used in this format string.
+    ///
+    /// # Arguments
+    /// - `fields`: The [`Fields`] that the format string was parsed with.
+    /// - `bounds`: A list of field types and their bounds to extend.
+    fn bounds<'f>(&self, fields: &'f Fields, bounds: &mut Vec<(&'f Type, Punctuated<TypeParamBound, Token![+]>)>) {
+        for (i, trts) in &self.uses {
+            let ty: &Type = &fields.iter().nth(*i).unwrap().ty;
+            for trt in trts {
+                bounds.push((ty, trait_bounds(&std_path("fmt", trt))));
+            }
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Resolves a format string argument to a field.
+///
+/// # Arguments
+/// - `lit`: The [`LitStr`] that contains the format string (used for errors).
+/// - `fields`: The [`Fields`] that the format string may refer to.
+/// - `name`: The name of the argument, either a field identifier or a tuple index.
+///
+/// # Returns
+/// The index of the field in `fields`.
+///
+/// # Errors
+/// This function errors if there is no field with the given name.
+fn resolve_field(lit: &LitStr, fields: &Fields, name: &str) -> Result<usize, Error> {
+    match fields {
+        Fields::Named(n) => n
+            .named
+            .iter()
+            .position(|f| f.ident.as_ref().map(|i| i.unraw() == name).unwrap_or(false))
+            .ok_or_else(|| Error::new(lit.span(), format!("Unknown field {name:?} in format string (use field names to refer to named fields)"))),
+        Fields::Unnamed(u) => name
+            .parse::<usize>()
+            .ok()
+            .filter(|i| *i < u.unnamed.len())
+            .ok_or_else(|| Error::new(lit.span(), format!("Unknown field {name:?} in format string (use indices to refer to unnamed fields)"))),
+        Fields::Unit => Err(Error::new(lit.span(), format!("Unknown field {name:?} in format string (there are no fields)"))),
+    }
+}
+
+/// Parses a format spec (the bit after the colon) in search of the formatting trait and any
+/// references to other fields.
+///
+/// # Arguments
+/// - `lit`: The [`LitStr`] that contains the format string (used for errors).
+/// - `fields`: The [`Fields`] that the format string may refer to.
+/// - `spec`: The spec to parse.
+///
+/// # Returns
+/// A tuple of the spec rewritten to refer to bindings, the name of the formatting trait and a list
+/// of fields used as width or precision.
+///
+/// # Errors
+/// This function errors if the spec was malformed or referred to unknown fields.
+fn parse_spec(lit: &LitStr, fields: &Fields, spec: &str) -> Result<(String, &'static str, Vec<usize>), Error> {
+    let chars: Vec<char> = spec.chars().collect();
+    let mut res: String = String::with_capacity(spec.len());
+    let mut args: Vec<usize> = Vec::new();
+    let mut i: usize = 0;
+
+    // Fill & align, sign, alternate and zero-padding
+    if chars.len() >= 2 && matches!(chars[1], '<' | '^' | '>') {
+        res.extend(&chars[..2]);
+        i += 2;
+    } else if !chars.is_empty() && matches!(chars[0], '<' | '^' | '>') {
+        res.push(chars[0]);
+        i += 1;
+    }
+    if i < chars.len() && matches!(chars[i], '+' | '-') {
+        res.push(chars[i]);
+        i += 1;
+    }
+    if i < chars.len() && chars[i] == '#' {
+        res.push(chars[i]);
+        i += 1;
+    }
+    if i < chars.len() && chars[i] == '0' && chars.get(i + 1) != Some(&'$') {
+        res.push(chars[i]);
+        i += 1;
+    }
+
+    // Width and precision, which may refer to other arguments
+    // NOTE: A count is either an integer, or an argument followed by `$`. Anything else (e.g.,
+    // the `x` in `08x`) is left for the type.
+    let mut count = |i: &mut usize, res: &mut String| -> Result<(), Error> {
+        let start: usize = *i;
+        while *i < chars.len() && chars[*i].is_ascii_digit() {
+            *i += 1;
+        }
+        let digits: usize = *i;
+        while *i < chars.len() && (chars[*i].is_alphanumeric() || chars[*i] == '_') {
+            *i += 1;
+        }
+        let word: String = chars[start..*i].iter().collect();
+        if *i < chars.len() && chars[*i] == '$' {
+            let field: usize = resolve_field(lit, fields, &word)?;
+            res.push_str(&binding_name(fields, field));
+            res.push('$');
+            args.push(field);
+            *i += 1;
+        } else {
+            // Keep only the integer, if any; the rest is the type
+            res.extend(&chars[start..digits]);
+            *i = digits;
+        }
+        Ok(())
+    };
+    count(&mut i, &mut res)?;
+    if i < chars.len() && chars[i] == '.' {
+        res.push('.');
+        i += 1;
+        if i < chars.len() && chars[i] == '*' {
+            return Err(Error::new(lit.span(), "Precision '.*' is not supported in format strings (use a named precision instead)"));
+        }
+        count(&mut i, &mut res)?;
+    }
+
+    // The rest is the type
+    let ty: String = chars[i..].iter().collect();
+    let trt: &'static str = match ty.as_str() {
+        "" => "Display",
+        "?" | "x?" | "X?" => "Debug",
+        "x" => "LowerHex",
+        "X" => "UpperHex",
+        "o" => "Octal",
+        "b" => "Binary",
+        "e" => "LowerExp",
+        "E" => "UpperExp",
+        "p" => "Pointer",
+        ty => return Err(Error::new(lit.span(), format!("Unknown format type {ty:?}"))),
+    };
+    res.push_str(&ty);
+    Ok((res, trt, args))
+}
+
+/// Registers that a field is used by a format string.
+///
+/// # Arguments
+/// - `uses`: The list of uses to extend.
+/// - `field`: The index of the used field.
+/// - `trt`: The formatting trait it's used with, if any.
+fn add_use(uses: &mut Vec<(usize, Vec<&'static str>)>, field: usize, trt: Option<&'static str>) {
+    let trts: &mut Vec<&'static str> = match uses.iter().position(|(i, _)| *i == field) {
+        Some(pos) => &mut uses[pos].1,
+        None => {
+            uses.push((field, Vec::new()));
+            &mut uses.last_mut().unwrap().1
+        },
+    };
+    if let Some(trt) = trt {
+        if !trts.contains(&trt) {
+            trts.push(trt);
+        }
+    }
+}
+
+/// Generates the name of the binding for a particular field.
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] the field is a part of.
+/// - `i`: The index of the field.
+///
+/// # Returns
+/// The name of the binding.
+fn binding_name(fields: &Fields, i: usize) -> String {
+    let f: &Field = fields.iter().nth(i).unwrap();
+    // NOTE: Format strings don't support raw identifiers, but do accept keywords as names
+    f.ident.as_ref().map(|ident| ident.unraw().to_string()).unwrap_or_else(|| format!("field{i}"))
+}
+
+/// Generates the identifier of the binding for a particular field.
+///
+/// This is the same as [`binding_name()`], except that it keeps raw identifiers raw (e.g.,
+/// `r#type`).
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] the field is a part of.
+/// - `i`: The index of the field.
+///
+/// # Returns
+/// The identifier of the binding.
+fn binding_ident(fields: &Fields, i: usize) -> Ident {
+    let f: &Field = fields.iter().nth(i).unwrap();
+    f.ident.clone().unwrap_or_else(|| Ident::new(&format!("field{i}"), Span::call_site()))
+}
+
+/// Parses `#[display("...")]` on variant attributes.
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to parse.
+///
+/// # Returns
+/// The format string given, if any.
+///
+/// # Errors
+/// This function fails if we failed to parse the attribute.
+fn parse_variant_attrs(attrs: &[Attribute]) -> Result<Option<LitStr>, Error> {
+    let mut fmt: Option<LitStr> = None;
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(l) if l.path.is_ident("display") => {
+                fmt = Some(attr.parse_args()?);
+            },
+
+            // Anything else, we ignore
+            _ => continue,
+        }
+    }
+    Ok(fmt)
+}
+
+/// Extracts the first paragraph of a doc comment to use as format string.
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to find the doc comment in.
+///
+/// # Returns
+/// The first paragraph of the doc comment, with all lines joined by spaces, or [`None`] if there
+/// was no doc comment.
+fn parse_doc_attrs(attrs: &[Attribute]) -> Option<LitStr> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut span: Option<Span> = None;
+    for attr in attrs {
+        match &attr.meta {
+            Meta::NameValue(nv) if nv.path.is_ident("doc") => {
+                if let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = &nv.value {
+                    // NOTE: Block comments may span multiple lines in one attribute
+                    lines.extend(lit.value().split('\n').map(|l| l.trim().to_string()));
+                    span.get_or_insert(lit.span());
+                }
+            },
+
+            // Anything else, we ignore
+            _ => continue,
+        }
+    }
+
+    // Only take the first paragraph
+    let para: Vec<&str> = lines.iter().map(String::as_str).skip_while(|l| l.is_empty()).take_while(|l| !l.is_empty()).collect();
+    if para.is_empty() {
+        return None;
+    }
+    Some(LitStr::new(&para.join(" "), span.unwrap_or_else(Span::call_site)))
+}
+
+/// Builds the necessary formatter implementation.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] which we will scan to collect the genreics.
+/// - `fmt`: The toplevel format string, if any.
+/// - `doc`: Whether to use doc comments as format strings for anything without an explicit one.
+///
+/// # Returns
+/// A [`TokenStream2`] that can be used for the impl and the list of field types to bind.
+///
+/// # Errors
+/// This function fails if any of the format strings is missing or malformed.
+#[allow(clippy::type_complexity)]
+fn build_fmt_impl<'i>(
+    input: &'i DeriveInput,
+    fmt: Option<&LitStr>,
+    doc: bool,
+) -> Result<(TokenStream2, Vec<(&'i Type, Punctuated<TypeParamBound, Token![+]>)>), Error> {
+    let mut bounds: Vec<(&'i Type, Punctuated<TypeParamBound, Token![+]>)> = Vec::new();
+
+    // Match based on the data type
+    match &input.data {
+        Data::Enum(e) => {
+            // Build the impls for every variant
+            let mut variants: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
+            for variant in &e.variants {
+                let variant_name = &variant.ident;
+                let lit: LitStr = match parse_variant_attrs(&variant.attrs)? {
+                    Some(lit) => lit,
+                    None if doc => parse_doc_attrs(&variant.attrs).ok_or_else(|| {
+                        Error::new(variant_name.span(), "Expected a doc comment for this variant (or a format string, e.g., `#[display(\"...\")]`)")
+                    })?,
+                    None => match fmt {
+                        Some(lit) => lit.clone(),
+                        None => return Err(Error::new(variant_name.span(), "Expected a format string for this variant (e.g., `#[display(\"...\")]`)")),
+                    },
+                };
+                let fmt = Format::parse(&lit, &variant.fields)?;
+                fmt.bounds(&variant.fields, &mut bounds);
+
+                // Write the match arm
+                let pattern = fmt.pattern(quote! { Self::#variant_name }, &variant.fields);
+                let write = fmt.write(&variant.fields);
+                variants.push(quote! { #pattern => #write, });
+            }
+
+            // Build the full match
+            if !variants.is_empty() {
+                Ok((
+                    quote! {
+                        match self {
+                            #(#variants)*
+                        }
+                    },
+                    bounds,
+                ))
+            } else {
+                Ok((quote! { ::std::unreachable!() }, bounds))
+            }
+        },
+        Data::Struct(s) => {
+            let lit: LitStr = match fmt {
+                Some(lit) => lit.clone(),
+                None if doc => parse_doc_attrs(&input.attrs).ok_or_else(|| {
+                    Error::new(input.ident.span(), "Expected a doc comment for this struct (or a format string, e.g., `#[display(\"...\")]`)")
+                })?,
+                None => return Err(Error::new(input.ident.span(), "Expected a format string for this struct (e.g., `#[display(\"...\")]`)")),
+            };
+            let fmt = Format::parse(&lit, &s.fields)?;
+            fmt.bounds(&s.fields, &mut bounds);
+
+            // Write the destructuring
+            let pattern = fmt.pattern(quote! { Self }, &s.fields);
+            let write = fmt.write(&s.fields);
+            Ok((
+                quote! {
+                    let #pattern = self;
+                    #write
+                },
+                bounds,
+            ))
+        },
+        Data::Union(_) => Err(Error::new(input.ident.span(), "The `Display` derive macro can only be used on structs and enums")),
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Display` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn display(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Parse the toplevel attributes
+    let (toplevel, attrs) = match parse_toplevel_attrs::<DisplayAttr>("display", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let mut fmt: Option<LitStr> = None;
+    let mut doc: bool = false;
+    for attr in attrs {
+        match attr {
+            DisplayAttr::Format(lit) => fmt = Some(lit),
+            DisplayAttr::Doc => doc = true,
+        }
+    }
+
+    // Build the body first, as that tells us which fields need to be bound
+    let (fmt, bounds) = match build_fmt_impl(&input, fmt.as_ref(), doc) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the general impl
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &std_path("fmt", "Display"), bounds);
+
+    // Done, build the impl
+    let name = &input.ident;
+    quote! {
+        impl #impl_gen ::std::fmt::Display for #name #ty_gen #where_clause {
+            #[inline]
+            fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                #fmt
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,306 @@
+//  ERROR.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 13:02:37
+//  Last edited:
+//    19 Oct 2026, 09:33:41
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Error`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens as _, quote};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Field, Fields, Ident, Lifetime, Meta, Path, PredicateType, Token, Type, TypeParamBound, TypePath,
+    WherePredicate, parse_macro_input,
+};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, member, parse_toplevel_attrs, std_path, trait_bounds};
+
+
+/***** HELPERS *****/
+/// Defines the role a field plays in an error.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum FieldRole {
+    /// It's just some field.
+    None,
+    /// It's the source of the error (`#[source]`).
+    Source,
+    /// It's the source of the error _and_ we can convert from it (`#[from]`).
+    From,
+    /// It's a backtrace that is captured when converting (`#[backtrace]`).
+    Backtrace,
+}
+
+/// Defines what we learned from the fields of a struct or variant.
+struct ErrorFields<'f> {
+    /// The source field, if any, and whether it's also a `#[from]`-field.
+    source: Option<(usize, &'f Field, bool)>,
+    /// The backtrace fields.
+    backtraces: Vec<usize>,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Parses `#[source]`, `#[from]` and `#[backtrace]` on field attributes.
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to parse.
+///
+/// # Returns
+/// The [`FieldRole`] of the field.
+///
+/// # Errors
+/// This function fails if a field was given multiple roles or arguments to any of them.
+fn parse_field_attrs(attrs: &[Attribute]) -> Result<FieldRole, Error> {
+    let mut role: FieldRole = FieldRole::None;
+    for attr in attrs {
+        let (new_role, span): (FieldRole, Span) = match &attr.meta {
+            Meta::Path(p) if p.is_ident("source") => (FieldRole::Source, p.span()),
+            Meta::Path(p) if p.is_ident("from") => (FieldRole::From, p.span()),
+            Meta::Path(p) if p.is_ident("backtrace") => (FieldRole::Backtrace, p.span()),
+            Meta::List(l) if l.path.is_ident("source") || l.path.is_ident("backtrace") => {
+                return Err(Error::new(l.span(), format!("`#[{}]` does not accept any arguments", l.path.to_token_stream())));
+            },
+
+            // Anything else, we ignore (including `#[from(...)]`, which belongs to the `From`-macro)
+            _ => continue,
+        };
+        if role != FieldRole::None {
+            return Err(Error::new(span, "A field can only be one of `#[source]`, `#[from]` or `#[backtrace]`"));
+        }
+        role = new_role;
+    }
+    Ok(role)
+}
+
+/// Scans the fields of a struct or variant for their roles.
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] to scan.
+///
+/// # Returns
+/// An [`ErrorFields`] describing the important fields.
+///
+/// # Errors
+/// This function fails if there are multiple source fields, or if a `#[from]`-field has siblings
+/// that we can't initialize.
+fn parse_fields(fields: &Fields) -> Result<ErrorFields<'_>, Error> {
+    let mut res = ErrorFields { source: None, backtraces: Vec::new() };
+    let mut others: Vec<&Field> = Vec::new();
+    for (i, f) in fields.iter().enumerate() {
+        match parse_field_attrs(&f.attrs)? {
+            FieldRole::None => others.push(f),
+            FieldRole::Backtrace => res.backtraces.push(i),
+            role @ (FieldRole::Source | FieldRole::From) => {
+                if res.source.is_some() {
+                    return Err(Error::new(f.span(), "There can only be one `#[source]` or `#[from]` field"));
+                }
+                res.source = Some((i, f, role == FieldRole::From));
+            },
+        }
+    }
+
+    // If it's a from-field, then all others must be backtraces
+    if let Some((_, _, true)) = res.source {
+        if let Some(other) = others.first() {
+            return Err(Error::new(other.span(), "Cannot generate a `From`-impl for a `#[from]`-field if there are other fields (except for `#[backtrace]`)"));
+        }
+    }
+    Ok(res)
+}
+
+/// Builds the `source()`-implementation and any `From`-impls.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] which we will scan to collect the genreics.
+///
+/// # Returns
+/// A [`TokenStream2`] that can be used for the `source()`-impl, a list of source field types to
+/// bind and a list of `(type, constructor)`-pairs to generate `From`-impls for.
+///
+/// # Errors
+/// This function fails if any of the attributes were illegal.
+#[allow(clippy::type_complexity)]
+fn build_source_impl(input: &DeriveInput) -> Result<(TokenStream2, Vec<&Type>, Vec<(&Type, TokenStream2)>), Error> {
+    let mut tys: Vec<&Type> = Vec::new();
+    let mut froms: Vec<(&Type, TokenStream2)> = Vec::new();
+
+    // Generates the `From`-constructor for the given fields
+    let from_impl = |path: TokenStream2, fields: &Fields, src: usize, backtraces: &[usize]| -> TokenStream2 {
+        let source = member(fields, src);
+        let backtraces = backtraces.iter().map(|i| member(fields, *i));
+        quote! {
+            #path {
+                #source: __source,
+                #(#backtraces: ::std::convert::From::from(::std::backtrace::Backtrace::capture()),)*
+            }
+        }
+    };
+
+    // Match based on the data type
+    match &input.data {
+        Data::Enum(e) => {
+            // Build the impls for every variant
+            let mut variants: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
+            for variant in &e.variants {
+                let variant_name = &variant.ident;
+                let fields = parse_fields(&variant.fields)?;
+                if let Some((i, f, from)) = fields.source {
+                    let member = member(&variant.fields, i);
+                    variants.push(quote! {
+                        Self::#variant_name { #member: __source, .. } => ::std::option::Option::Some((*__source).__as_dyn_error()),
+                    });
+                    tys.push(&f.ty);
+                    if from {
+                        froms.push((&f.ty, from_impl(quote! { Self::#variant_name }, &variant.fields, i, &fields.backtraces)));
+                    }
+                }
+            }
+
+            // Build the full match
+            Ok((
+                quote! {
+                    match self {
+                        #(#variants)*
+                        #[allow(unreachable_patterns)]
+                        _ => ::std::option::Option::None,
+                    }
+                },
+                tys,
+                froms,
+            ))
+        },
+        Data::Struct(s) => {
+            let fields = parse_fields(&s.fields)?;
+            match fields.source {
+                Some((i, f, from)) => {
+                    let member = member(&s.fields, i);
+                    tys.push(&f.ty);
+                    if from {
+                        froms.push((&f.ty, from_impl(quote! { Self }, &s.fields, i, &fields.backtraces)));
+                    }
+                    Ok((quote! { ::std::option::Option::Some(self.#member.__as_dyn_error()) }, tys, froms))
+                },
+                None => Ok((quote! { ::std::option::Option::None }, tys, froms)),
+            }
+        },
+        Data::Union(_) => Err(Error::new(input.ident.span(), "The `Error` derive macro can only be used on structs and enums")),
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Error` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Build the body first, as that tells us which fields need to be bound
+    let (source, tys, froms) = match build_source_impl(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the general impl
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("error", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("error", "Error");
+    let (impl_gen, ty_gen, mut where_clause) = extract_generics_with(
+        &toplevel,
+        &input,
+        &target,
+        tys.into_iter().map(|ty| {
+            let mut bounds = trait_bounds(&target);
+            bounds.push(TypeParamBound::Lifetime(Lifetime::new("'static", Span::call_site())));
+            (ty, bounds)
+        }),
+    );
+    // NOTE: `Error` requires `Debug` and `Display`, which may have bounds of their own
+    where_clause.push(WherePredicate::Type(PredicateType {
+        lifetimes:   None,
+        bounded_ty:  Type::Path(TypePath { qself: None, path: Ident::new("Self", Span::call_site()).into() }),
+        colon_token: Default::default(),
+        bounds:      {
+            let mut bounds: Punctuated<TypeParamBound, Token![+]> = trait_bounds(&std_path("fmt", "Debug"));
+            bounds.extend(trait_bounds(&std_path("fmt", "Display")));
+            bounds
+        },
+    }));
+
+    // Build the `From`-impls
+    let name = &input.ident;
+    let from_target: Path = std_path("convert", "From");
+    let froms = froms.into_iter().map(|(ty, from)| {
+        let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &from_target, None);
+        quote! {
+            impl #impl_gen ::std::convert::From<#ty> for #name #ty_gen #where_clause {
+                #[inline]
+                fn from(__source: #ty) -> Self {
+                    #from
+                }
+            }
+        }
+    });
+
+    // Done, build the impl
+    quote! {
+        impl #impl_gen ::std::error::Error for #name #ty_gen #where_clause {
+            #[inline]
+            fn source(&self) -> ::std::option::Option<&(dyn ::std::error::Error + 'static)> {
+                // NOTE: We can't simply cast the source, as trait objects (e.g., in a
+                // `Box<dyn Error>`) aren't `Error` themselves. Instead, this trait is found through
+                // auto-deref for both.
+                #[allow(dead_code)]
+                trait __AsDynError {
+                    fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static);
+                }
+                impl<__T: ::std::error::Error + 'static> __AsDynError for __T {
+                    #[inline]
+                    fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) { self }
+                }
+                impl __AsDynError for dyn ::std::error::Error + 'static {
+                    #[inline]
+                    fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) { self }
+                }
+                impl __AsDynError for dyn ::std::error::Error + ::std::marker::Send + 'static {
+                    #[inline]
+                    fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) { self }
+                }
+                impl __AsDynError for dyn ::std::error::Error + ::std::marker::Sync + 'static {
+                    #[inline]
+                    fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) { self }
+                }
+                impl __AsDynError for dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync + 'static {
+                    #[inline]
+                    fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) { self }
+                }
+
+                #source
+            }
+        }
+
+        #(#froms)*
+    }
+    .into()
+}
@@ -0,0 +1,105 @@
+//  FIELDS.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 04:55:40
+//  Last edited:
+//    19 Oct 2026, 10:31:02
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Fields`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Path, Type, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, member, parse_field_attrs, parse_toplevel_attrs, std_path, trait_bounds};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Collects the fields that aren't skipped.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to collect the fields of.
+///
+/// # Returns
+/// A list of the members referring to the fields, and their types.
+///
+/// # Errors
+/// This function fails if the input is not a struct, or if we failed to parse the fields'
+/// attributes.
+fn collect_fields(input: &DeriveInput) -> Result<Vec<(TokenStream2, &Type)>, Error> {
+    let fields: &Fields = match &input.data {
+        Data::Struct(s) => &s.fields,
+        Data::Enum(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), "The `Fields` derive macro can only be used on structs")),
+    };
+
+    // NOTE: We don't filter the fields beforehand, as that would change the indices of unnamed ones
+    let mut res: Vec<(TokenStream2, &Type)> = Vec::with_capacity(fields.len());
+    for (i, f) in fields.iter().enumerate() {
+        if parse_field_attrs("fields", &f.attrs)? {
+            continue;
+        }
+        res.push((member(fields, i), &f.ty));
+    }
+    Ok(res)
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Fields` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impls.
+pub fn fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Collect the fields
+    let fields = match collect_fields(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("fields", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // The constants don't need any bounds, but visiting requires the fields to be `Debug`
+    let target: Path = std_path("fmt", "Debug");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+    let (_, _, visit_where_clause) = extract_generics_with(&toplevel, &input, &target, fields.iter().map(|(_, ty)| (*ty, trait_bounds(&target))));
+
+    // Done, build the impls
+    let name = &input.ident;
+    let members = fields.iter().map(|(member, _)| member);
+    let tys = fields.iter().map(|(_, ty)| ty);
+    let visits = fields.iter().map(|(member, _)| quote! { __f(::std::stringify!(#member), &self.#member); });
+    quote! {
+        impl #impl_gen #name #ty_gen #where_clause {
+            /// The names of all fields, in declaration order.
+            pub const FIELD_NAMES: &'static [&'static str] = &[#(::std::stringify!(#members)),*];
+
+            /// The types of all fields, in declaration order.
+            pub const FIELD_TYPES: &'static [&'static str] = &[#(::std::stringify!(#tys)),*];
+        }
+
+        impl #impl_gen #name #ty_gen #visit_where_clause {
+            /// Calls the given closure with the name and value of every field, in declaration order.
+            #[inline]
+            pub fn for_each_field(&self, __f: &mut dyn ::std::ops::FnMut(&'static str, &dyn ::std::fmt::Debug)) {
+                #(#visits)*
+            }
+        }
+    }
+    .into()
+}
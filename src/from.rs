@@ -0,0 +1,100 @@
+//  FROM.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 14:40:12
+//  Last edited:
+//    19 Oct 2026, 09:35:12
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `From`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Field, Path, Type, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, member, parse_toplevel_attrs, single_field_variants, std_path};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Collects the types to generate `From`-impls for.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to collect the impls of.
+///
+/// # Returns
+/// A list of `(type, constructor)`-pairs, where the constructor builds `Self` from a
+/// `__value` of that type.
+///
+/// # Errors
+/// This function fails if a struct does not have exactly one field, if we failed to parse the
+/// `#[from(skip)]`-attributes, or if two variants would generate conflicting impls.
+fn build_from_impls(input: &DeriveInput) -> Result<Vec<(&Type, TokenStream2)>, Error> {
+    match &input.data {
+        Data::Enum(e) => Ok(single_field_variants("from", "From", &e.variants, &input.generics.params)?
+            .into_iter()
+            .map(|(variant, f)| {
+                let variant_name = &variant.ident;
+                let member = member(&variant.fields, 0);
+                (&f.ty, quote! { Self::#variant_name { #member: __value } })
+            })
+            .collect()),
+        Data::Struct(s) => {
+            if s.fields.len() != 1 {
+                return Err(Error::new(input.ident.span(), "The `From` derive macro can only be used on structs with exactly one field"));
+            }
+            let f: &Field = s.fields.iter().next().unwrap();
+            let member = member(&s.fields, 0);
+            Ok(vec![(&f.ty, quote! { Self { #member: __value } })])
+        },
+        Data::Union(_) => Err(Error::new(input.ident.span(), "The `From` derive macro can only be used on structs and enums")),
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `From` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn from(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Collect the types to convert from
+    let froms = match build_from_impls(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the impls. No need to bound the fields, as they are simply moved.
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("from", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("convert", "From");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // Done, build the impls
+    let name = &input.ident;
+    let froms = froms.into_iter().map(|(ty, from)| {
+        quote! {
+            impl #impl_gen ::std::convert::From<#ty> for #name #ty_gen #where_clause {
+                #[inline]
+                fn from(__value: #ty) -> Self {
+                    #from
+                }
+            }
+        }
+    });
+    quote! { #(#froms)* }.into()
+}
@@ -0,0 +1,376 @@
+//  FROM STR.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 16:03:51
+//  Last edited:
+//    19 Oct 2026, 09:42:19
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `FromStr`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, GenericParam, Ident, Lifetime, LifetimeParam, LitStr, Meta, Path, Token, parse_macro_input};
+
+use crate::common::{extract_generics_with, parse_toplevel_attrs, std_path, to_snake_case};
+
+
+/***** HELPERS *****/
+/// Defines the toplevel attributes of the `FromStr`-macro.
+enum FromStrAttr {
+    /// Renames all variants according to some case convention.
+    RenameAll(LitStr),
+    /// Matches strings case-insensitively.
+    CaseInsensitive,
+}
+impl Parse for FromStrAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "rename_all" {
+            input.parse::<Token![=]>()?;
+            Ok(Self::RenameAll(input.parse()?))
+        } else if ident == "case_insensitive" {
+            Ok(Self::CaseInsensitive)
+        } else {
+            Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+/// Defines the attributes of the `FromStr`-macro on variants.
+enum VariantAttr {
+    /// Gives the variant a different name.
+    Rename(LitStr),
+    /// Gives the variant an additional name to parse from.
+    Alias(LitStr),
+}
+impl Parse for VariantAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "rename" {
+            input.parse::<Token![=]>()?;
+            Ok(Self::Rename(input.parse()?))
+        } else if ident == "alias" {
+            input.parse::<Token![=]>()?;
+            Ok(Self::Alias(input.parse()?))
+        } else {
+            Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+
+
+/// Defines the case conventions supported by `rename_all`.
+#[derive(Clone, Copy)]
+enum RenameRule {
+    /// `lowercase`
+    Lower,
+    /// `UPPERCASE`
+    Upper,
+    /// `PascalCase`
+    Pascal,
+    /// `camelCase`
+    Camel,
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `SCREAMING-KEBAB-CASE`
+    ScreamingKebab,
+}
+impl RenameRule {
+    /// Parses a rule from its string representation.
+    ///
+    /// # Arguments
+    /// - `lit`: The [`LitStr`] to parse.
+    ///
+    /// # Returns
+    /// The parsed rule.
+    ///
+    /// # Errors
+    /// This function fails if the rule is unknown.
+    fn parse(lit: &LitStr) -> Result<Self, Error> {
+        match lit.value().as_str() {
+            "lowercase" => Ok(Self::Lower),
+            "UPPERCASE" => Ok(Self::Upper),
+            "PascalCase" => Ok(Self::Pascal),
+            "camelCase" => Ok(Self::Camel),
+            "snake_case" => Ok(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+            "kebab-case" => Ok(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebab),
+            other => Err(Error::new(
+                lit.span(),
+                format!(
+                    "Unknown case convention {other:?} (expected one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \
+                     \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\" or \"SCREAMING-KEBAB-CASE\")"
+                ),
+            )),
+        }
+    }
+
+    /// Applies the rule to a variant name.
+    ///
+    /// Variant names are assumed to be in `PascalCase`, where every uppercase letter starts a new
+    /// word.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the variant to rename.
+    ///
+    /// # Returns
+    /// The renamed variant name.
+    fn apply(self, name: &str) -> String {
+        match self {
+            Self::Lower => name.to_ascii_lowercase(),
+            Self::Upper => name.to_ascii_uppercase(),
+            Self::Pascal => name.into(),
+            Self::Camel => {
+                let mut chars = name.chars();
+                chars.next().map(|c| c.to_ascii_lowercase().to_string() + chars.as_str()).unwrap_or_default()
+            },
+            Self::Snake => to_snake_case(name),
+            Self::ScreamingSnake => Self::Snake.apply(name).to_ascii_uppercase(),
+            Self::Kebab => Self::Snake.apply(name).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake.apply(name).replace('_', "-"),
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Parses `#[from_str(rename = "...", alias = "...")]` on variant attributes.
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to parse.
+///
+/// # Returns
+/// The new name of the variant (if any), and a list of its aliases.
+///
+/// # Errors
+/// This function fails if we failed to parse the attributes, or if the variant was renamed
+/// multiple times.
+fn parse_variant_attrs(attrs: &[Attribute]) -> Result<(Option<LitStr>, Vec<LitStr>), Error> {
+    let mut rename: Option<LitStr> = None;
+    let mut aliases: Vec<LitStr> = Vec::new();
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(l) if l.path.is_ident("from_str") => {
+                for attr in attr.parse_args_with(Punctuated::<VariantAttr, Token![,]>::parse_terminated)? {
+                    match attr {
+                        VariantAttr::Rename(lit) => {
+                            if rename.is_some() {
+                                return Err(Error::new(lit.span(), "A variant can only be renamed once"));
+                            }
+                            rename = Some(lit);
+                        },
+                        VariantAttr::Alias(lit) => aliases.push(lit),
+                    }
+                }
+            },
+
+            // Anything else, we ignore
+            _ => continue,
+        }
+    }
+    Ok((rename, aliases))
+}
+
+/// Builds the arms of the `from_str()`- and `as_str()`-implementations.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to build the impls for.
+/// - `rule`: Any [`RenameRule`] to apply to variants that aren't explicitly renamed.
+/// - `case_insensitive`: Whether names are matched case-insensitively.
+///
+/// # Returns
+/// A tuple of the `from_str()`-body, a list of `as_str()`-arms and a list of all (primary)
+/// variant names.
+///
+/// # Errors
+/// This function fails if the input is not an enum, if any of its variants has fields, if we
+/// failed to parse the variant attributes or if multiple variants would parse from the same string.
+fn build_from_str_impl(input: &DeriveInput, rule: Option<RenameRule>, case_insensitive: bool) -> Result<(TokenStream2, Vec<TokenStream2>, Vec<String>), Error> {
+    let e = match &input.data {
+        Data::Enum(e) => e,
+        Data::Struct(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), "The `FromStr` derive macro can only be used on enums")),
+    };
+
+    // Build the impls for every variant
+    let mut parses: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
+    let mut as_strs: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
+    let mut names: Vec<String> = Vec::with_capacity(e.variants.len());
+    let mut seen: Vec<(String, &Ident)> = Vec::with_capacity(e.variants.len());
+    for variant in &e.variants {
+        let variant_name = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => {},
+            Fields::Named(_) | Fields::Unnamed(_) => {
+                return Err(Error::new(variant.fields.span(), "The `FromStr` derive macro can only be used on enums without fields"));
+            },
+        }
+
+        // Find the names of this variant
+        let (rename, aliases) = parse_variant_attrs(&variant.attrs)?;
+        let name: String = match rename {
+            Some(lit) => lit.value(),
+            None => rule.map(|rule| rule.apply(&variant_name.to_string())).unwrap_or_else(|| variant_name.to_string()),
+        };
+        let mut strs: Vec<(String, Span)> = vec![(name.clone(), variant_name.span())];
+        strs.extend(aliases.into_iter().map(|lit| (lit.value(), lit.span())));
+
+        // Assert these are unique
+        for (s, span) in &strs {
+            let key: String = if case_insensitive { s.to_ascii_lowercase() } else { s.clone() };
+            if let Some((_, other)) = seen.iter().find(|(k, _)| *k == key) {
+                return Err(Error::new(*span, format!("Variant `{variant_name}` would parse from {s:?}, but so does variant `{other}`")));
+            }
+            seen.push((key, variant_name));
+        }
+
+        // Build the arms
+        let strs = strs.into_iter().map(|(s, _)| s);
+        parses.push(if case_insensitive {
+            quote! { if #(__s.eq_ignore_ascii_case(#strs))||* { return ::std::result::Result::Ok(Self::#variant_name); } }
+        } else {
+            quote! { #(#strs)|* => ::std::result::Result::Ok(Self::#variant_name), }
+        });
+        as_strs.push(quote! { Self::#variant_name => #name, });
+        names.push(name);
+    }
+
+    // Build the full parse impl
+    let error_name = format_ident!("Parse{}Error", input.ident);
+    let error = quote! { ::std::result::Result::Err(#error_name { raw: __s.into() }) };
+    let parse: TokenStream2 = if case_insensitive {
+        quote! {
+            #(#parses)*
+            #error
+        }
+    } else {
+        quote! {
+            match __s {
+                #(#parses)*
+                _ => #error,
+            }
+        }
+    };
+    Ok((parse, as_strs, names))
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `FromStr` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn from_str(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Parse the toplevel attributes
+    let (toplevel, attrs) = match parse_toplevel_attrs::<FromStrAttr>("from_str", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let mut rule: Option<RenameRule> = None;
+    let mut case_insensitive: bool = false;
+    for attr in attrs {
+        match attr {
+            FromStrAttr::RenameAll(lit) => match RenameRule::parse(&lit) {
+                Ok(r) => rule = Some(r),
+                Err(err) => return err.into_compile_error().into(),
+            },
+            FromStrAttr::CaseInsensitive => case_insensitive = true,
+        }
+    }
+
+    // Build the bodies
+    let (parse, as_strs, names) = match build_from_str_impl(&input, rule, case_insensitive) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the impls. No need to bound anything, as there are no fields.
+    let target: Path = std_path("str", "FromStr");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+    // NOTE: The `TryFrom<&str>`-impl needs an additional lifetime for the string
+    let lifetime = Lifetime::new("'__a", Span::call_site());
+    let (mut str_impl_gen, _, _) = extract_generics_with(&toplevel, &input, &target, None);
+    str_impl_gen.push(GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+
+    // Prepare the error type
+    let vis = &input.vis;
+    let name = &input.ident;
+    let sname = name.to_string();
+    let error_name = format_ident!("Parse{}Error", name);
+    let error_doc = format!("Error returned when failing to parse a [`{sname}`] from a string.");
+    let expected: String = if !names.is_empty() {
+        format!(" (expected one of {})", names.iter().map(|n| format!("{n:?}")).collect::<Vec<String>>().join(", "))
+    } else {
+        String::new()
+    };
+
+    // Done, build the impls
+    quote! {
+        #[doc = #error_doc]
+        #[derive(::std::clone::Clone, ::std::fmt::Debug, ::std::cmp::Eq, ::std::cmp::PartialEq)]
+        #vis struct #error_name {
+            /// The string that we failed to parse.
+            pub raw: ::std::string::String,
+        }
+        impl ::std::fmt::Display for #error_name {
+            #[inline]
+            fn fmt(&self, __f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                // NOTE: Passed as an argument, as the names may contain braces
+                ::std::write!(__f, ::std::concat!("Unknown ", #sname, " {:?}{}"), self.raw, #expected)
+            }
+        }
+        impl ::std::error::Error for #error_name {}
+
+        impl #impl_gen ::std::str::FromStr for #name #ty_gen #where_clause {
+            type Err = #error_name;
+
+            #[inline]
+            fn from_str(__s: &str) -> ::std::result::Result<Self, #error_name> {
+                #parse
+            }
+        }
+
+        impl #str_impl_gen ::std::convert::TryFrom<&#lifetime str> for #name #ty_gen #where_clause {
+            type Error = #error_name;
+
+            #[inline]
+            fn try_from(__s: &#lifetime str) -> ::std::result::Result<Self, #error_name> { <Self as ::std::str::FromStr>::from_str(__s) }
+        }
+
+        impl #impl_gen #name #ty_gen #where_clause {
+            /// Returns the name of this variant, as it would be parsed by `FromStr`.
+            #[inline]
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    #(#as_strs)*
+                }
+            }
+        }
+    }
+    .into()
+}
@@ -154,9 +154,9 @@ pub fn hash(input: TokenStream) -> TokenStream {
         leading_colon: Some(Default::default()),
         segments:      {
             let mut segments = Punctuated::new();
-            segments.push(PathSegment { ident: Ident::new("std".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("hash".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("Hash".into(), Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("hash", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("Hash", Span::call_site()), arguments: PathArguments::None });
             segments
         },
     }) {
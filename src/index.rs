@@ -0,0 +1,131 @@
+//  INDEX.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 22:03:51
+//  Last edited:
+//    17 Oct 2026, 22:31:17
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Index`- and `IndexMut`-macros.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    DeriveInput, GenericArgument, GenericParam, Ident, Path, PredicateType, Type, TypeParam, TypePath, WherePredicate, parse_macro_input,
+};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, find_marked_field, parse_toplevel_attrs, path_with_args, std_path, trait_bounds};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Builds a where-predicate `TYPE: TRAIT<__Idx>`.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to bound.
+/// - `target`: The [`Path`] of the trait to bound on.
+/// - `idx`: The [`Type`] of the index to give as argument to the trait.
+///
+/// # Returns
+/// A [`WherePredicate`] encoding the bound.
+fn predicate(ty: Type, target: Path, idx: &Type) -> WherePredicate {
+    WherePredicate::Type(PredicateType {
+        lifetimes:   None,
+        bounded_ty:  ty,
+        colon_token: Default::default(),
+        bounds:      trait_bounds(&path_with_args(target, Some(GenericArgument::Type(idx.clone())))),
+    })
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Index` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn index(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Find the field to forward to
+    let (member, field) = match find_marked_field(&input, "index", "Index") {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("index", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Inject the index type, and bound the field on being indexable with it
+    let target: Path = std_path("ops", "Index");
+    let idx_ident = Ident::new("__Idx", Span::call_site());
+    let idx = Type::Path(TypePath { qself: None, path: idx_ident.clone().into() });
+    let (mut impl_gen, ty_gen, mut where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+    impl_gen.push(GenericParam::Type(TypeParam::from(idx_ident)));
+    where_clause.push(predicate(field.ty.clone(), target, &idx));
+
+    // Done, build the impl
+    let name = &input.ident;
+    let ty = &field.ty;
+    quote! {
+        impl #impl_gen ::std::ops::Index<#idx> for #name #ty_gen #where_clause {
+            type Output = <#ty as ::std::ops::Index<#idx>>::Output;
+
+            #[inline]
+            fn index(&self, __index: #idx) -> &<#ty as ::std::ops::Index<#idx>>::Output { ::std::ops::Index::index(&self.#member, __index) }
+        }
+    }
+    .into()
+}
+
+/// Actual implementation of the `IndexMut` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn index_mut(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Find the field to forward to
+    let (member, field) = match find_marked_field(&input, "index", "IndexMut") {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("index_mut", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Inject the index type, and bound the field on being mutably indexable with it
+    let target: Path = std_path("ops", "IndexMut");
+    let idx_ident = Ident::new("__Idx", Span::call_site());
+    let idx = Type::Path(TypePath { qself: None, path: idx_ident.clone().into() });
+    let (mut impl_gen, ty_gen, mut where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+    impl_gen.push(GenericParam::Type(TypeParam::from(idx_ident)));
+    where_clause.push(predicate(field.ty.clone(), target, &idx));
+
+    // Done, build the impl
+    let name = &input.ident;
+    let ty = &field.ty;
+    quote! {
+        impl #impl_gen ::std::ops::IndexMut<#idx> for #name #ty_gen #where_clause {
+            #[inline]
+            fn index_mut(&mut self, __index: #idx) -> &mut <#ty as ::std::ops::Index<#idx>>::Output {
+                ::std::ops::IndexMut::index_mut(&mut self.#member, __index)
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,226 @@
+//  ITER.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 20:58:12
+//  Last edited:
+//    19 Oct 2026, 10:05:29
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `IntoIterator`-, `FromIterator`- and `Extend`-macros.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Error, Field, GenericArgument, GenericParam, Ident, Lifetime, LifetimeParam, Path, PredicateType, Type, TypeParam,
+    TypePath, TypeReference, WherePredicate, parse_macro_input,
+};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, member, parse_toplevel_attrs, path_with_args, std_path, trait_bounds};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Finds the field of a newtype to forward to.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to find the field in.
+/// - `trait_name`: The name of the trait for which the impl is generated. Only used for errors.
+///
+/// # Returns
+/// The member referring to the field, and the field itself.
+///
+/// # Errors
+/// This function fails if the input is not a struct with exactly one field.
+fn find_field<'i>(input: &'i DeriveInput, trait_name: &str) -> Result<(TokenStream2, &'i Field), Error> {
+    match &input.data {
+        Data::Struct(s) if s.fields.len() == 1 => Ok((member(&s.fields, 0), s.fields.iter().next().unwrap())),
+        Data::Struct(_) | Data::Enum(_) | Data::Union(_) => {
+            Err(Error::new(input.ident.span(), format!("The `{trait_name}` derive macro can only be used on structs with exactly one field")))
+        },
+    }
+}
+
+/// Builds a where-predicate `TYPE: TRAIT`.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to bound.
+/// - `target`: The [`Path`] of the trait to bound on.
+///
+/// # Returns
+/// A [`WherePredicate`] encoding the bound.
+fn predicate(ty: Type, target: &Path) -> WherePredicate {
+    WherePredicate::Type(PredicateType { lifetimes: None, bounded_ty: ty, colon_token: Default::default(), bounds: trait_bounds(target) })
+}
+
+/// Builds the type of a generic parameter with the given name.
+///
+/// # Arguments
+/// - `name`: The name of the parameter.
+///
+/// # Returns
+/// A [`Type`] referring to it.
+fn param_type(name: &str) -> Type { Type::Path(TypePath { qself: None, path: Ident::new(name, Span::call_site()).into() }) }
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `IntoIterator` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impls.
+pub fn into_iterator(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Find the field to forward to
+    let (member, field) = match find_field(&input, "IntoIterator") {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("into_iterator", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("iter", "IntoIterator");
+
+    // The owned impl is bound on the field type
+    let ty: &Type = &field.ty;
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, Some((ty, trait_bounds(&target))));
+
+    // The by-reference impls need an additional lifetime, and are always bound on the reference
+    // NOTE: This makes them simply not apply if the field can't be iterated by reference.
+    let lifetime = Lifetime::new("'__a", Span::call_site());
+    let (mut ref_impl_gen, _, mut ref_where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+    ref_impl_gen.push(GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    let (mut mut_impl_gen, _, mut mut_where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+    mut_impl_gen.push(GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    let ref_ty = Type::Reference(TypeReference {
+        and_token:  Default::default(),
+        lifetime:   Some(lifetime.clone()),
+        mutability: None,
+        elem:       Box::new(ty.clone()),
+    });
+    let mut_ty = Type::Reference(TypeReference {
+        and_token:  Default::default(),
+        lifetime:   Some(lifetime.clone()),
+        mutability: Some(Default::default()),
+        elem:       Box::new(ty.clone()),
+    });
+    ref_where_clause.push(predicate(ref_ty.clone(), &target));
+    mut_where_clause.push(predicate(mut_ty.clone(), &target));
+
+    // Done, build the impls
+    let name = &input.ident;
+    quote! {
+        impl #impl_gen ::std::iter::IntoIterator for #name #ty_gen #where_clause {
+            type Item = <#ty as ::std::iter::IntoIterator>::Item;
+            type IntoIter = <#ty as ::std::iter::IntoIterator>::IntoIter;
+
+            #[inline]
+            fn into_iter(self) -> <#ty as ::std::iter::IntoIterator>::IntoIter { ::std::iter::IntoIterator::into_iter(self.#member) }
+        }
+
+        impl #ref_impl_gen ::std::iter::IntoIterator for &#lifetime #name #ty_gen #ref_where_clause {
+            type Item = <#ref_ty as ::std::iter::IntoIterator>::Item;
+            type IntoIter = <#ref_ty as ::std::iter::IntoIterator>::IntoIter;
+
+            #[inline]
+            fn into_iter(self) -> <#ref_ty as ::std::iter::IntoIterator>::IntoIter { ::std::iter::IntoIterator::into_iter(&self.#member) }
+        }
+
+        impl #mut_impl_gen ::std::iter::IntoIterator for &#lifetime mut #name #ty_gen #mut_where_clause {
+            type Item = <#mut_ty as ::std::iter::IntoIterator>::Item;
+            type IntoIter = <#mut_ty as ::std::iter::IntoIterator>::IntoIter;
+
+            #[inline]
+            fn into_iter(self) -> <#mut_ty as ::std::iter::IntoIterator>::IntoIter { ::std::iter::IntoIterator::into_iter(&mut self.#member) }
+        }
+    }
+    .into()
+}
+
+/// Actual implementation of the `FromIterator` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn from_iterator(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Find the field to forward to
+    let (member, field) = match find_field(&input, "FromIterator") {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("from_iterator", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Inject the item type, and bound the field on being collectable from it
+    let target: Path = std_path("iter", "FromIterator");
+    let item: Type = param_type("__A");
+    let (mut impl_gen, ty_gen, mut where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+    impl_gen.push(GenericParam::Type(TypeParam::from(Ident::new("__A", Span::call_site()))));
+    where_clause.push(predicate(field.ty.clone(), &path_with_args(target, Some(GenericArgument::Type(item.clone())))));
+
+    // Done, build the impl
+    let name = &input.ident;
+    quote! {
+        impl #impl_gen ::std::iter::FromIterator<#item> for #name #ty_gen #where_clause {
+            #[inline]
+            fn from_iter<__I: ::std::iter::IntoIterator<Item = #item>>(__iter: __I) -> Self {
+                Self { #member: ::std::iter::FromIterator::from_iter(__iter) }
+            }
+        }
+    }
+    .into()
+}
+
+/// Actual implementation of the `Extend` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn extend(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Find the field to forward to
+    let (member, field) = match find_field(&input, "Extend") {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("extend", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Inject the item type, and bound the field on being extendable with it
+    let target: Path = std_path("iter", "Extend");
+    let item: Type = param_type("__A");
+    let (mut impl_gen, ty_gen, mut where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+    impl_gen.push(GenericParam::Type(TypeParam::from(Ident::new("__A", Span::call_site()))));
+    where_clause.push(predicate(field.ty.clone(), &path_with_args(target, Some(GenericArgument::Type(item.clone())))));
+
+    // Done, build the impl
+    let name = &input.ident;
+    quote! {
+        impl #impl_gen ::std::iter::Extend<#item> for #name #ty_gen #where_clause {
+            #[inline]
+            fn extend<__I: ::std::iter::IntoIterator<Item = #item>>(&mut self, __iter: __I) { ::std::iter::Extend::extend(&mut self.#member, __iter) }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,155 @@
+//  KIND.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 03:04:51
+//  Last edited:
+//    19 Oct 2026, 10:18:51
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Kind`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Data, DeriveInput, Error, Ident, Path, parenthesized, parse_macro_input};
+
+use crate::common::{extract_generics_with, parse_field_attrs_with, parse_toplevel_attrs, std_path};
+
+
+/***** HELPERS *****/
+/// Defines the attributes of the `Kind`-macro, both toplevel and on variants.
+enum KindAttr {
+    /// Forwards an attribute to the generated enum (or its variant).
+    Attr(TokenStream2),
+}
+impl Parse for KindAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "attr" {
+            let content;
+            parenthesized!(content in input);
+            Ok(Self::Attr(content.parse()?))
+        } else {
+            Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Builds the variants of the kind enum and the arms of `kind()`.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to build the kind enum for.
+/// - `kind_name`: The name of the kind enum.
+///
+/// # Returns
+/// A list of variants for the kind enum, and a list of match arms mapping variants to them.
+///
+/// # Errors
+/// This function fails if the input is not an enum, or if we failed to parse the variants'
+/// attributes.
+fn build_kind_variants(input: &DeriveInput, kind_name: &Ident) -> Result<(Vec<TokenStream2>, Vec<TokenStream2>), Error> {
+    let e = match &input.data {
+        Data::Enum(e) => e,
+        Data::Struct(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), "The `Kind` derive macro can only be used on enums")),
+    };
+    let sname = input.ident.to_string();
+
+    // Mirror every variant
+    let mut variants: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
+    let mut arms: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
+    for variant in &e.variants {
+        let variant_name = &variant.ident;
+        let (skip, attrs) = parse_field_attrs_with::<KindAttr>("kind", &variant.attrs)?;
+        if skip {
+            return Err(Error::new(variant_name.span(), "Variants cannot be skipped by the `Kind` derive macro"));
+        }
+        let attrs = attrs.into_iter().map(|KindAttr::Attr(attr)| attr);
+
+        let doc = format!("The kind of a [`{sname}::{variant_name}`].");
+        variants.push(quote! {
+            #[doc = #doc]
+            #(#[#attrs])*
+            #variant_name,
+        });
+        arms.push(quote! { Self::#variant_name { .. } => #kind_name::#variant_name, });
+    }
+    Ok((variants, arms))
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Kind` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the kind enum and the impl.
+pub fn kind(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let kind_name = format_ident!("{}Kind", name);
+
+    // Build the variants first
+    let (variants, arms) = match build_kind_variants(&input, &kind_name) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the impl. No need to bound anything, as we don't touch the fields.
+    let (toplevel, attrs) = match parse_toplevel_attrs::<KindAttr>("kind", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let attrs = attrs.into_iter().map(|KindAttr::Attr(attr)| attr);
+    let target: Path = std_path("marker", "Sized");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // Done, build the enum and the impl
+    let vis = &input.vis;
+    let doc = format!("The kind of a [`{name}`], i.e., its variants without any of their fields.");
+    quote! {
+        #[doc = #doc]
+        // NOTE: The kind enum has no generics, so the standard library's derives are just as good
+        // (and don't depend on the name under which this crate is available)
+        #[derive(
+            ::std::clone::Clone,
+            ::std::marker::Copy,
+            ::std::fmt::Debug,
+            ::std::cmp::Eq,
+            ::std::hash::Hash,
+            ::std::cmp::Ord,
+            ::std::cmp::PartialEq,
+            ::std::cmp::PartialOrd,
+        )]
+        #(#[#attrs])*
+        #vis enum #kind_name {
+            #(#variants)*
+        }
+
+        impl #impl_gen #name #ty_gen #where_clause {
+            /// Returns the kind of this value, i.e., which variant it is.
+            #[inline]
+            pub const fn kind(&self) -> #kind_name {
+                // NOTE: Dereferenced, so that this also works for enums without variants
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+    }
+    .into()
+}
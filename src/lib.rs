@@ -10,8 +10,8 @@
 //
 //  Description:
 //!   A small Rust crate that provides alternatives for Rust's builtin `Clone`-, `Copy`-, `Debug`-,
-//!   `Eq`-, `Hash`-, `Ord`-, `PartialEq`- and `PartialOrd`-derive macros that apply more accurate trait
-//!   bounds for generated impls.
+//!   `Default`-, `Eq`-, `Hash`-, `Ord`-, `PartialEq`- and `PartialOrd`-derive macros that apply more
+//!   accurate trait bounds for generated impls.
 //!
 //!
 //!   # Installation
@@ -63,12 +63,41 @@
 //!   - `Clone`
 //!   - `Copy`
 //!   - `Debug`
+//!   - `Default`
 //!   - `Eq`
 //!   - `Hash`
 //!   - `Ord`
 //!   - `PartialEq`
 //!   - `PartialOrd`
 //!
+//!   Next to that, the crate also provides macros for traits that don't have a builtin derive:
+//!   - `Add`, `Sub`, `Mul`, `Div`, `Rem`, `Neg`, `Not`, `BitAnd`, `BitOr`, `BitXor`, `Shl` and `Shr`, and
+//!     their `*Assign`-counterparts
+//!   - `AsRef`, `AsMut` and `Borrow`
+//!   - `Deref` and `DerefMut`
+//!   - `Discriminant`
+//!   - `Display`
+//!   - `Error`
+//!   - `Extend`, `FromIterator` and `IntoIterator`
+//!   - `From`
+//!   - `FromStr`
+//!   - `Index` and `IndexMut`
+//!   - `TryInto`
+//!   - `UnsafeSend` and `UnsafeSync`
+//!
+//!   Additionally, some macros generate inherent methods instead of trait impls:
+//!   - `Accessors`
+//!   - `Builder`
+//!   - `Diff`
+//!   - `Fields`
+//!   - `Kind`
+//!   - `New`
+//!   - `Patch`
+//!   - `PinProject`
+//!   - `ShapeHash`
+//!   - `VariantHelpers`
+//!   - `VariantMeta`
+//!
 //!   There are also some macros for other crates, unlocked by feature:
 //!   - [_serde_](https://serde.rs):
 //!     - `Serialize`
@@ -114,6 +143,157 @@
 //!   See the respective macro's docs for more information, or see the [`skip.rs`](./examples/skip.rs)-
 //!   example in the repository.
 //!
+//!   ## `Default`: Custom field values
+//!   The `Default` derive macro allows you to annotate fields with `#[default(...)]` to give them a
+//!   custom value instead of their own `Default`-impl. It accepts either an arbitrary expression (e.g.,
+//!   `#[default(42)]`) or a function to call (e.g., `#[default(with = Vec::new)]`). Fields with a custom
+//!   value are not bound on `Default`.
+//!
+//!   Like the builtin one, enums must mark their default variant with `#[default]`. Unlike the builtin
+//!   one, however, this variant may have fields.
+//!
+//!   ## Operators: Field-wise arithmetic
+//!   The operator derive macros (e.g., `Add` or `MulAssign`) apply the operator to every field of a
+//!   struct, and thus simply forward to the inner type of newtypes. Every field type is bound on the
+//!   operator (e.g., `FIELD: Add<Output = FIELD>`), except for `PhantomData`-fields, which are simply
+//!   created anew. Note that this also means that `Shl` and `Shr` shift by `Self`, not by an integer.
+//!
+//!   ## `Accessors`: Getters and setters
+//!   The `Accessors` derive macro generates a getter (`foo()`), mutable getter (`foo_mut()`), setter
+//!   (`set_foo()`) and builder-style setter (`with_foo()`) for every field `foo`, or for every field
+//!   index of tuple structs (e.g., `get_0()`). Fields can be marked with `#[accessors(skip)]` to not
+//!   generate them, `#[accessors(copy)]` to return the field by value and `#[accessors(vis = "...")]` to
+//!   give them a visibility other than `pub`.
+//!
+//!   ## `AsRef`, `AsMut` and `Borrow`: Referring to fields
+//!   The `AsRef`, `AsMut` and `Borrow` derive macros refer to the only field of a struct, or to the fields
+//!   marked with `#[as_ref]`, `#[as_mut]` or `#[borrow]`, respectively. Give a list of types (e.g.,
+//!   `#[as_ref(str)]`) to forward through the field's own impls instead. Because `Borrow` must be
+//!   consistent with `Hash` and `PartialEq`, all other fields must be skipped by those macros (which
+//!   is checked on a best-effort basis).
+//!
+//!   ## `Builder`: Builders with required fields
+//!   The `Builder` derive macro generates a `FooBuilder` for a struct `Foo`, with a chainable setter for
+//!   every field. Its `build()`-method returns a `FooBuilderError` naming the missing fields if not all
+//!   required fields are set. Fields can be marked with `#[builder(default)]` to make them optional,
+//!   `#[builder(into)]` to make their setter accept anything `Into` their type and `#[builder(skip)]` to
+//!   not generate a setter at all.
+//!
+//!   ## `Deref` and `DerefMut`: Forwarding to a field
+//!   The `Deref` and `DerefMut` derive macros dereference a struct to its only field, or to the field
+//!   marked with `#[deref]` if it has multiple. The field is never bound.
+//!
+//!   ## `Diff`: Reporting differing fields
+//!   The `Diff` derive macro generates a `diff()`-method that returns the path and both values of every
+//!   field in which two values differ. Fields marked with `#[diff(nested)]` are recursed into, and fields
+//!   marked with `#[diff(skip)]` or `#[partial_eq(skip)]` are left out.
+//!
+//!   ## `Discriminant`: Integer conversions
+//!   The `Discriminant` derive macro converts enums without fields to and from their discriminant. It
+//!   generates `TryFrom<INT>`, `From<Enum> for INT` and a `const fn from_discriminant()`, where `INT` is
+//!   the integer type given in `#[repr(...)]`.
+//!
+//!   ## `Display`: Format strings
+//!   The `Display` derive macro is driven by format strings given with `#[display("...")]`, either on
+//!   the type itself or on every variant of an enum. Fields can be referred to by name (e.g., `{foo}`) or
+//!   by index (e.g., `{0}` or `{}`), and any format specs supported by `format!()` can be used. Only
+//!   fields that are used in the format string are bound, and then only on the trait they are formatted
+//!   with (e.g., `{foo:?}` bounds on `Debug`).
+//!
+//!   Alternatively, give `#[display(doc)]` to use the first paragraph of the doc comment of every
+//!   variant (or of the type itself) as its format string instead.
+//!
+//!   ## `Error`: Field roles
+//!   The `Error` derive macro generates a `source()`-implementation that returns the field marked with
+//!   `#[source]`, if any. Alternatively, mark it with `#[from]` to also generate a `From`-impl for the
+//!   field's type. Fields marked with `#[backtrace]` are captured when using that impl. Only the source
+//!   fields are bound, on `Error + 'static`.
+//!
+//!   ## `Extend`, `FromIterator` and `IntoIterator`: Collection newtypes
+//!   The `Extend`, `FromIterator` and `IntoIterator` derive macros forward to the field of a newtype
+//!   (e.g., `struct Names(Vec<String>)`). `IntoIterator` is implemented by value, by reference and by
+//!   mutable reference. Instead of the generics, the field's type is bound on the trait (e.g.,
+//!   `Vec<T>: IntoIterator`). Like for the other macros, use `#[better_derive(bound = (...))]` or the
+//!   trait-specific attribute (e.g., `#[into_iterator(bound = (...))]`) to override this.
+//!
+//!   ## `Fields`: Field names and visiting
+//!   The `Fields` derive macro generates `FIELD_NAMES`, `FIELD_TYPES` and `for_each_field()` for structs,
+//!   which allow listing their fields generically (e.g., for logging). Fields can be left out using
+//!   `#[fields(skip)]`.
+//!
+//!   ## `From`: Newtypes and variants
+//!   The `From` derive macro converts from the field type of newtypes, or from the field type of every
+//!   single-field variant of an enum. Variants can opt-out using `#[from(skip)]`, which is needed when
+//!   two variants share a field type. The fields are never bound, as they are simply moved.
+//!
+//!   ## `FromStr`: Variant names
+//!   The `FromStr` derive macro parses enums without fields from the names of their variants, and also
+//!   generates `TryFrom<&str>` and an `as_str()`-method. Use `#[from_str(rename_all = "...")]` to apply a
+//!   case convention (e.g., `"kebab-case"`) and `#[from_str(case_insensitive)]` to ignore case. Variants
+//!   can be renamed using `#[from_str(rename = "...")]` or given additional names using
+//!   `#[from_str(alias = "...")]`.
+//!
+//!   ## `Index` and `IndexMut`: Forwarding indexing
+//!   The `Index` and `IndexMut` derive macros index the only field of a struct, or the field marked with
+//!   `#[index]` if it has multiple. The index type is an additional generic of the impl, so the field's
+//!   type is bound on it instead (e.g., `Vec<T>: Index<Idx>`).
+//!
+//!   ## `Kind`: Fieldless mirror enums
+//!   The `Kind` derive macro generates a `FooKind` for an enum `Foo`, which has the same variants but
+//!   without their fields, and a `Foo::kind()` to obtain it. The kind enum derives `Clone`,
+//!   `Copy`, `Debug`, `Eq`, `Hash`, `Ord`, `PartialEq` and `PartialOrd`. Forward any other attributes to
+//!   it (or its variants) using `#[kind(attr(...))]`.
+//!
+//!   ## `New`: Constructors
+//!   The `New` derive macro generates a `new()`-constructor for structs, or one constructor for every
+//!   variant of an enum (e.g., `circle()` for `Shape::Circle`). Fields are taken as parameters in
+//!   declaration order, unless marked with `#[new(default)]` or `#[new(value = ...)]`. Use
+//!   `#[new(const)]` to generate `const fn`s.
+//!
+//!   ## `Patch`: Layered configuration
+//!   The `Patch` derive macro generates a `FooPatch` for a struct `Foo`, where every field is wrapped in an
+//!   `Option`, together with `Foo::apply()` to overwrite the fields set in a patch and `FooPatch::merge()`
+//!   to layer two patches. Fields marked with `#[patch(nested)]` use their own type's patch instead. The
+//!   patch implements `Default`, and other attributes (e.g., derives) can be forwarded to it using
+//!   `#[patch(attr(...))]`.
+//!
+//!   ## `PinProject`: Pin-projections
+//!   The `PinProject` derive macro generates a `FooProj<'__pin>` for a struct `Foo` and a `Foo::project()`
+//!   that projects a `Pin<&mut Foo>` to it, where fields marked with `#[pin]` are pinned and the others
+//!   aren't. It also implements `Unpin` for `Foo`, bound only on the types of the pinned fields, and
+//!   prevents implementing `Drop` for it.
+//!
+//!   ## `ShapeHash`: Schema fingerprints
+//!   The `ShapeHash` derive macro generates a `const SHAPE_HASH: u64` that fingerprints the shape of a type
+//!   (i.e., the names of the type, its fields and its variants, the types of its fields and its
+//!   discriminants), e.g., to invalidate cached values when it changes. Fields marked with
+//!   `#[shape_hash(nested)]` also mix in the fingerprint of their type. Fields and variants marked with
+//!   `#[shape_hash(skip)]` or `#[serialize(skip)]` don't affect it.
+//!
+//!   ## `TryInto`: Extracting variant fields
+//!   The `TryInto` derive macro generates `TryFrom<Enum> for Field` and `TryFrom<&'a Enum> for &'a Field`
+//!   for the field type of every single-field variant of an enum. If the enum is of another variant, the
+//!   original value is returned as error. Like with `From`, variants can opt-out using
+//!   `#[try_into(skip)]`.
+//!
+//!   ## `UnsafeSend` and `UnsafeSync`: Documented thread-safety
+//!   The `UnsafeSend` and `UnsafeSync` derive macros unsafely implement `Send` and `Sync`, e.g., for types
+//!   holding raw pointers. Every field is bound on the trait, including those that don't use generics, so
+//!   fields that aren't `Send` or `Sync` must be exempted explicitly with `#[send(assume)]` or
+//!   `#[sync(assume)]`. This way, the unsafe assumptions are documented per field.
+//!
+//!   ## `VariantHelpers`: Checking and projecting variants
+//!   The `VariantHelpers` derive macro generates `is_foo()`, `as_foo()`, `as_foo_mut()`, `into_foo()` and
+//!   `unwrap_foo()` for every variant `Foo` of an enum, where the latter four are only generated for
+//!   variants with fields. Variants with a single field return it directly, and those with multiple a
+//!   tuple. Variants can opt-out using `#[variant_helpers(skip)]`.
+//!
+//!   ## `VariantMeta`: Variant names and iteration
+//!   The `VariantMeta` derive macro generates `VARIANT_NAMES`, `VARIANT_COUNT` and `variant_name()` for
+//!   enums. For enums without fields, it also generates `ALL`, `iter()`, `next()` and `prev()`. Variants
+//!   can be renamed using `#[variant_meta(rename = "...")]` or left out using `#[variant_meta(skip)]`.
+//!   By default, the names match those printed by the `Debug` derive macro (e.g., `Enum::Variant`).
+//!
 //!   ## Features
 //!   This crate supports the following features:
 //!   - `serde`: Unlock the `Serialize`-macro.
@@ -142,22 +322,184 @@
 //
 
 // Modules
+mod accessors;
+mod as_ref;
+mod builder;
 mod clone;
 mod common;
 mod copy;
 mod debug;
+mod default;
+mod deref;
+mod diff;
+mod discriminant;
+mod display;
 mod eq;
+mod error;
+mod fields;
+mod from;
+mod from_str;
 mod hash;
+mod index;
+mod iter;
+mod kind;
+mod new;
+mod ops;
 mod ord;
 mod partial_eq;
 mod partial_ord;
+mod patch;
+mod pin_project;
 mod serialize;
+mod shape_hash;
+mod try_into;
+mod unsafe_marker;
+mod variant_helpers;
+mod variant_meta;
 
 // Imports
+use ops::Op;
 use proc_macro::TokenStream;
 
 
 /***** STANDARD LIB *****/
+/// Defines a derive macro for [`AsMut`](std::convert::AsMut) that forwards to a field.
+///
+/// This macro works exactly the same as [`AsRef`](derive@AsRef), except that fields are marked
+/// with `#[as_mut]` or `#[as_mut(TYPE, ...)]` instead.
+///
+/// # Examples
+/// ```rust
+/// use better_derive::AsMut;
+///
+/// #[derive(AsMut)]
+/// struct Buffer<T> {
+///     #[as_mut(Vec<T>, [T])]
+///     data: Vec<T>,
+///     cursor: usize,
+/// }
+///
+/// let mut buffer = Buffer { data: vec![1, 2, 3], cursor: 0 };
+/// let cursor = buffer.cursor;
+/// AsMut::<[u32]>::as_mut(&mut buffer)[cursor] = 42;
+/// AsMut::<Vec<u32>>::as_mut(&mut buffer).push(4);
+/// assert_eq!(buffer.data, [42, 2, 3, 4]);
+/// ```
+#[inline]
+#[proc_macro_derive(AsMut, attributes(better_derive, as_mut))]
+pub fn as_mut(input: TokenStream) -> TokenStream { as_ref::as_mut(input) }
+
+/// Defines a derive macro for [`AsRef`](std::convert::AsRef) that forwards to a field.
+///
+/// Rust doesn't have a builtin derive macro for `AsRef`. This macro implements `AsRef<FIELD>` for
+/// structs with exactly one field, or for every field marked with `#[as_ref]` if there are
+/// multiple.
+///
+/// Because the field is simply borrowed, the generated impls don't bound any of the fields.
+///
+///
+/// # Forwarding
+/// Instead of referring to the field's type itself, you can also give a list of types to forward
+/// to using `#[as_ref(TYPE, ...)]`. Then, for every `TYPE`, `AsRef<TYPE>` is implemented by calling
+/// the field's own `AsRef<TYPE>`-impl. For example, `#[as_ref(str)]` on a `String`-field
+/// implements `AsRef<str>`. In this case, the field's type is bound on `AsRef<TYPE>` if it
+/// depends on any generics.
+///
+/// Every type can only be referred to once. A bare type parameter (e.g., `T`) may be any type, and
+/// can thus only be combined with types that use it (e.g., `Vec<T>`):
+/// ```compile_fail
+/// use better_derive::AsRef;
+///
+/// #[derive(AsRef)]
+/// struct Named<T> {
+///     #[as_ref]
+///     value: T,
+///     // `T` may be a `String` too
+///     #[as_ref]
+///     name:  String,
+/// }
+/// ```
+///
+///
+/// # Examples
+/// ```rust
+/// use std::path::Path;
+///
+/// use better_derive::AsRef;
+///
+/// #[derive(AsRef)]
+/// struct Name(String);
+///
+/// #[derive(AsRef)]
+/// struct File<T> {
+///     #[as_ref(str, Path)]
+///     path: String,
+///     #[as_ref]
+///     contents: Vec<T>,
+/// }
+///
+/// let name = Name("Amy".into());
+/// assert_eq!(AsRef::<String>::as_ref(&name), "Amy");
+///
+/// let file = File { path: "/tmp/test.txt".into(), contents: vec![42] };
+/// assert_eq!(AsRef::<str>::as_ref(&file), "/tmp/test.txt");
+/// assert_eq!(AsRef::<Path>::as_ref(&file).extension().unwrap(), "txt");
+/// assert_eq!(AsRef::<Vec<i32>>::as_ref(&file), &[42]);
+/// ```
+#[inline]
+#[proc_macro_derive(AsRef, attributes(better_derive, as_ref))]
+pub fn as_ref(input: TokenStream) -> TokenStream { as_ref::as_ref(input) }
+
+/// Defines a derive macro for [`Borrow`](std::borrow::Borrow) that forwards to a field.
+///
+/// This macro works the same as [`AsRef`](derive@AsRef), except that fields are marked with
+/// `#[borrow]` or `#[borrow(TYPE, ...)]` instead, and that only one field can be marked.
+///
+/// Unlike `AsRef`, however, `Borrow` requires that the `Hash`- and `PartialEq`-impls of the
+/// borrowed type behave identically to those of your type. Otherwise, looking up your type in, e.g.,
+/// a [`HashMap`](std::collections::HashMap) by the borrowed type would give wrong results. As
+/// such, this macro requires that all other fields are skipped by [our](derive@Hash)
+/// [derives](derive@PartialEq) of those traits (e.g., using `#[better_derive(skip)]`).
+///
+/// Note that this is only a best-effort check, as the macro merely looks for the skip-attributes.
+/// It can't see whether `Hash` and `PartialEq` are derived by this crate at all, and neither does it
+/// consider `Eq`, `Ord` and `PartialOrd`, which must be consistent too if implemented. For
+/// hand-written impls, add the skip-attributes anyway (they are accepted by this macro too).
+///
+/// # Examples
+/// ```rust
+/// use std::collections::HashSet;
+///
+/// use better_derive::{Borrow, Eq, Hash, PartialEq};
+///
+/// #[derive(Borrow, Eq, Hash, PartialEq)]
+/// struct User {
+///     #[borrow(str)]
+///     name: String,
+///     #[hash(skip)]
+///     #[partial_eq(skip)]
+///     visits: usize,
+/// }
+///
+/// let mut users = HashSet::new();
+/// users.insert(User { name: "Amy".into(), visits: 42 });
+/// assert_eq!(users.get("Amy").unwrap().visits, 42);
+/// ```
+///
+/// ```compile_fail
+/// use better_derive::{Borrow, Eq, Hash, PartialEq};
+///
+/// #[derive(Borrow, Eq, Hash, PartialEq)]
+/// struct User {
+///     #[borrow(str)]
+///     name: String,
+///     visits: usize,
+/// }
+/// ```
+#[inline]
+#[proc_macro_derive(Borrow, attributes(better_derive, borrow, hash, partial_eq))]
+pub fn borrow(input: TokenStream) -> TokenStream { as_ref::borrow(input) }
+
 /// Defines a [`Clone`](derive@::std::clone::Clone)-like derive macro that's more lenient to
 /// generics.
 ///
@@ -261,235 +603,744 @@ pub fn copy(input: TokenStream) -> TokenStream { copy::copy(input) }
 ///
 /// assert_eq!(
 ///     format!("{:?}", PhantomStruct::<DebuglessType> { _t: PhantomData }),
-///     "PhantomStruct { _t: \
-///      PhantomData<rust_out::main::_doctest_main_src_lib_rs_251_0::DebuglessType> }"
+///     format!("PhantomStruct {{ _t: PhantomData<{}> }}", std::any::type_name::<DebuglessType>())
 /// )
 /// ```
 #[inline]
 #[proc_macro_derive(Debug, attributes(better_derive, debug))]
 pub fn debug(input: TokenStream) -> TokenStream { debug::debug(input) }
 
-/// Defines an [`Eq`](derive@::std::cmp::Eq)-like derive macro that's more lenient to generics.
+/// Defines a [`Default`](derive@::std::default::Default)-like derive macro that's more lenient to
+/// generics.
 ///
 /// In particular, the default derive macro enforces that all _generics_ implement
-/// [`Eq`](std::cmp::Eq). This is, however, too strict. Instead, all that's needed is that the
-/// _fields_ implement it, which may or may not require the generics to do so.
+/// [`Default`](std::default::Default). This is, however, too strict. Instead, all that's needed is
+/// that the _fields_ implement it, which may or may not require the generics to do so.
+///
+/// You can use this macro in exactly the same way as the builtin one. Like the builtin one, enums
+/// must mark one of their variants with `#[default]`. Unlike the builtin one, however, that
+/// variant may also have fields, which are initialized like the fields of a struct.
+///
+///
+/// # Custom field values
+/// You can optionally give fields a custom value with the `#[default(...)]`-attribute, which
+/// either accepts an arbitrary expression or a function to call with `#[default(with = ...)]`:
+/// ```rust
+/// use better_derive::Default;
+///
+/// #[derive(Default)]
+/// struct Foo {
+///     // Something that is fine with its default value
+///     boring_field: u32,
+///     // Something with a more interesting value
+///     #[default(42)]
+///     answer_field: u32,
+///     // Something computed by a function
+///     #[default(with = String::new)]
+///     string_field: String,
+/// }
+///
+/// let foo = Foo::default();
+/// assert_eq!(foo.boring_field, 0);
+/// assert_eq!(foo.answer_field, 42);
+/// assert_eq!(foo.string_field, "");
+/// ```
+/// Importantly, giving a custom value **also removes that field's type from the trait bounds**.
+/// If the field has a type that does not implement `Default`, you can simply give it a value
+/// yourself by using this attribute.
+///
+/// A field can only be given one value:
+/// ```compile_fail
+/// use better_derive::Default;
+///
+/// #[derive(Default)]
+/// struct Foo {
+///     #[default(42)]
+///     #[default(with = u32::default)]
+///     answer_field: u32,
+/// }
+/// ```
 ///
-/// You can use this macro in exactly the same way as the builtin one.
 ///
 /// # Examples
 /// ```rust
 /// use std::marker::PhantomData;
 ///
-/// use better_derive::{Eq, PartialEq};
+/// use better_derive::Default;
 ///
-/// struct EqlessType;
+/// struct DefaultlessType;
 ///
-/// #[derive(Eq, PartialEq)]
+/// #[derive(Default)]
 /// struct PhantomStruct<T> {
 ///     _t: PhantomData<T>,
 /// }
 ///
-/// assert!(
-///     PhantomStruct::<EqlessType> { _t: PhantomData }
-///         == PhantomStruct::<EqlessType> { _t: PhantomData }
-/// );
+/// #[derive(Default)]
+/// enum PhantomEnum<T> {
+///     Variant1,
+///     #[default]
+///     Variant2 {
+///         _t: PhantomData<T>,
+///         #[default(42)]
+///         answer: u32,
+///     },
+/// }
+///
+/// let p = PhantomStruct::<DefaultlessType>::default();
+/// assert!(matches!(PhantomEnum::<DefaultlessType>::default(), PhantomEnum::Variant2 { answer: 42, .. }));
 /// ```
 #[inline]
-#[proc_macro_derive(Eq, attributes(better_derive, eq))]
-pub fn eq(input: TokenStream) -> TokenStream { eq::eq(input) }
+#[proc_macro_derive(Default, attributes(better_derive, default))]
+pub fn default(input: TokenStream) -> TokenStream { default::default(input) }
 
-/// Defines a [`Hash`](derive@::std::hash::Hash)-like derive macro that's more lenient to generics.
+/// Defines a derive macro for [`Deref`](std::ops::Deref) that forwards to a field.
 ///
-/// In particular, the default derive macro enforces that all _generics_ implement
-/// [`Hash`](std::cmp::Hash). This is, however, too strict. Instead, all that's needed is
-/// that the _fields_ implement it, which may or may not require the generics to do so.
+/// Rust doesn't have a builtin derive macro for `Deref`. This macro implements it for structs by
+/// dereferencing to their only field, or to the field marked with `#[deref]` if there are multiple.
 ///
-/// You can use this macro in exactly the same way as the builtin one.
+/// Because the field is simply borrowed, the generated impl doesn't bound any of the fields.
 ///
 ///
-/// # Skipping fields
-/// You can optionally omit fields from the generated implementation with the `#[hash(skip)]`-
-/// attribute:
+/// # Examples
 /// ```rust
-/// use better_derive::Hash;
+/// use std::sync::Arc;
 ///
-/// #[derive(Hash)]
-/// struct Foo {
-///     // Something very interesting we want to hash
-///     dynamic_field: String,
-///     // Something very boring that never changes anyway
-///     #[hash(skip)]
-///     static_field:  (),
+/// use better_derive::{Deref, DerefMut};
+///
+/// struct Inner<T> {
+///     value: T,
+/// }
+///
+/// #[derive(Deref)]
+/// struct Handle<T>(Arc<Inner<T>>);
+///
+/// #[derive(Deref, DerefMut)]
+/// struct Labeled<T> {
+///     label: &'static str,
+///     #[deref]
+///     value: T,
 /// }
+///
+/// let handle = Handle(Arc::new(Inner { value: 42 }));
+/// assert_eq!(handle.value, 42);
+///
+/// let mut labeled = Labeled { label: "answer", value: vec![42] };
+/// labeled.push(43);
+/// assert_eq!(labeled.len(), 2);
+/// assert_eq!(labeled.label, "answer");
 /// ```
-/// Importantly, adding the skip-attribute **also removes that field's type from the trait
-/// bounds**. If the field has a tricky type to implement `Hash` for, or is very expensive to hash
-/// but will never vary between two instances, you can simply not do it by using this attribute.
+#[inline]
+#[proc_macro_derive(Deref, attributes(better_derive, deref))]
+pub fn deref(input: TokenStream) -> TokenStream { deref::deref(input) }
+
+/// Defines a derive macro for [`DerefMut`](std::ops::DerefMut) that forwards to a field.
 ///
+/// This macro works exactly the same as [`Deref`](derive@Deref), and can only be used together
+/// with it (or with a manual `Deref`-impl to the same field).
 ///
 /// # Examples
 /// ```rust
-/// use std::hash::{DefaultHasher, Hash as _, Hasher as _};
-/// use std::marker::PhantomData;
+/// use better_derive::{Deref, DerefMut};
 ///
-/// use better_derive::Hash;
+/// #[derive(Deref, DerefMut)]
+/// struct Wrapper<T>(Vec<T>);
 ///
-/// struct HashlessType;
+/// let mut wrapper = Wrapper(vec![1, 2]);
+/// wrapper.push(3);
+/// assert_eq!(*wrapper, [1, 2, 3]);
+/// ```
+#[inline]
+#[proc_macro_derive(DerefMut, attributes(better_derive, deref, deref_mut))]
+pub fn deref_mut(input: TokenStream) -> TokenStream { deref::deref_mut(input) }
+
+/// Defines a derive macro for converting enums without fields to and from integers.
 ///
-/// #[derive(Hash)]
-/// struct PhantomStruct<T> {
-///     _t: PhantomData<T>,
+/// Specifically, this macro generates a `const fn from_discriminant(value: INT) -> Option<Self>`,
+/// [`TryFrom<INT>`](std::convert::TryFrom) for your enum and [`From<Enum>`](std::convert::From)
+/// for `INT`. Here, `INT` is the integer type given in `#[repr(...)]`, or `isize` if there is
+/// none. Variants are matched on their discriminant, which may be given explicitly as a (possibly
+/// negative) integer literal.
+///
+/// If conversion fails, an error of a generated type `Invalid<NAME>Error` is returned. It has the
+/// same visibility as the enum, and carries the integer that failed to convert in its `raw`-field.
+///
+///
+/// # Examples
+/// ```rust
+/// use better_derive::Discriminant;
+///
+/// #[derive(Debug, Discriminant, PartialEq)]
+/// #[repr(i8)]
+/// enum Status {
+///     Failed = -1,
+///     Ok,
+///     Pending = 5,
+///     Unknown,
 /// }
 ///
-/// assert_eq!(
-///     {
-///         let mut state = DefaultHasher::default();
-///         PhantomStruct::<HashlessType> { _t: PhantomData }.hash(&mut state);
-///         state.finish()
-///     },
-///     15130871412783076140
-/// );
+/// const PENDING: Option<Status> = Status::from_discriminant(5);
+/// assert_eq!(PENDING, Some(Status::Pending));
+/// assert_eq!(Status::try_from(-1), Ok(Status::Failed));
+/// assert_eq!(Status::try_from(6), Ok(Status::Unknown));
+/// assert_eq!(i8::from(Status::Ok), 0);
+///
+/// let err = Status::try_from(3).unwrap_err();
+/// assert_eq!(err.raw, 3);
+/// assert_eq!(err.to_string(), "No variant of Status has discriminant 3");
 /// ```
 #[inline]
-#[proc_macro_derive(Hash, attributes(better_derive, hash))]
-pub fn hash(input: TokenStream) -> TokenStream { hash::hash(input) }
+#[proc_macro_derive(Discriminant, attributes(better_derive, discriminant))]
+pub fn discriminant(input: TokenStream) -> TokenStream { discriminant::discriminant(input) }
 
-/// Defines an [`Ord`](derive@::std::cmp::Ord)-like derive macro that's more lenient to generics.
+/// Defines a derive macro for [`Display`](std::fmt::Display) that is driven by format strings.
 ///
-/// In particular, the default derive macro enforces that all _generics_ implement
-/// [`Ord`](std::cmp::Ord). This is, however, too strict. Instead, all that's needed is that the
-/// _fields_ implement it, which may or may not require the generics to do so.
+/// Rust doesn't have a builtin derive macro for `Display`, as there isn't an obvious way to show
+/// a type to users. Instead, this macro lets you define a format string with the
+/// `#[display("...")]`-attribute, either on the type itself or on every variant of an enum (a
+/// type-level one is used for any variant without one). The format string can refer to fields by
+/// name (e.g., `{foo}`) or by index (e.g., `{0}` or `{}`) and supports the same format specs as
+/// [`format!()`].
 ///
-/// This macro will generate an implementation with the bounds described as above, but that will
-/// otherwise refer to the implementation of [`PartialOrd`](std::cmp::PartialOrd). As such, if
-/// you're deriving it, it is recommended to use [our derive macro](derive@PartialOrd) as well.
+/// Only the fields that are actually used in the format string are bound, and only on the trait
+/// they are formatted with (e.g., `{foo:?}` bounds on [`Debug`](std::fmt::Debug)).
 ///
-/// You can use this macro in exactly the same way as the builtin one.
 ///
-/// # Skipping fields
-/// You can optionally omit fields from the generated implementation with the
-/// `#[ord(skip)]`-attribute:
+/// # Format specs
+/// Widths and precisions can be given directly or refer to other fields, and fields with raw
+/// identifiers are referred to without the `r#`:
 /// ```rust
-/// use better_derive::{Eq, Ord, PartialEq, PartialOrd};
-///
-/// #[derive(Ord, PartialEq, Eq, PartialOrd)]
-/// struct Foo {
-///     // Something very interesting we want to compare
-///     dynamic_field: String,
-///     // Something very boring that never changes anyway
-///     #[ord(skip)]
-///     static_field:  (),
+/// use better_derive::Display;
+///
+/// #[derive(Display)]
+/// #[display("{0:08x}|{0:>8X}|{1:.3e}")]
+/// struct Reading(u32, f64);
+///
+/// #[derive(Display)]
+/// #[display("{type}: {value:.prec$}")]
+/// struct Value {
+///     r#type: &'static str,
+///     value:  f64,
+///     prec:   usize,
 /// }
+///
+/// assert_eq!(Reading(0xBEEF, 1234.5).to_string(), "0000beef|    BEEF|1.234e3");
+/// assert_eq!(Value { r#type: "ratio", value: 0.12345, prec: 2 }.to_string(), "ratio: 0.12");
 /// ```
-/// Importantly, adding the skip-attribute **also removes that field's type from the trait
-/// bounds**. If the field has a tricky type to implement `Ord` for, or is very expensive to
-/// compare but will never vary between two instances, you can simply not do it by using this
-/// attribute.
 ///
 ///
+/// # Doc comments
+/// Alternatively, you can use the `#[display(doc)]`-attribute to use the first paragraph of the
+/// doc comment of every variant (or of the type itself, for structs) as its format string:
+/// ```rust
+/// use better_derive::Display;
+///
+/// #[derive(Display)]
+/// #[display(doc)]
+/// enum Status {
+///     /// Everything is fine.
+///     Ok,
+///     /// Failed to open file {path:?}
+///     /// (error code {code}).
+///     ///
+///     /// This paragraph isn't part of the format string.
+///     FileError { path: String, code: i32 },
+///     // An explicit format string still takes precedence
+///     #[display("unknown")]
+///     Unknown,
+/// }
+///
+/// assert_eq!(Status::Ok.to_string(), "Everything is fine.");
+/// assert_eq!(
+///     Status::FileError { path: "foo.txt".into(), code: 2 }.to_string(),
+///     "Failed to open file \"foo.txt\" (error code 2)."
+/// );
+/// assert_eq!(Status::Unknown.to_string(), "unknown");
+/// ```
+/// Any variant without a doc comment (or explicit format string) results in an error.
+///
 ///
 /// # Examples
 /// ```rust
 /// use std::marker::PhantomData;
 ///
-/// use better_derive::{Eq, Ord, PartialEq, PartialOrd};
+/// use better_derive::Display;
 ///
-/// struct OrdlessType;
+/// struct DisplaylessType;
 ///
-/// #[derive(PartialEq, Ord, Eq, PartialOrd)]
+/// #[derive(Display)]
+/// #[display("Hello {name} with {_t:?}")]
 /// struct PhantomStruct<T> {
-///     _t: PhantomData<T>,
+///     name: &'static str,
+///     _t:   PhantomData<T>,
 /// }
 ///
-/// assert!(
-///     !(PhantomStruct::<OrdlessType> { _t: PhantomData }
-///         > PhantomStruct::<OrdlessType> { _t: PhantomData })
+/// #[derive(Display)]
+/// enum PhantomEnum<T> {
+///     #[display("Variant1({0}, {1:?})")]
+///     Variant1(u32, PhantomData<T>),
+///     #[display("Variant2 says {msg:>8}")]
+///     Variant2 { msg: String },
+/// }
+///
+/// assert_eq!(
+///     PhantomStruct::<DisplaylessType> { name: "world", _t: PhantomData }.to_string(),
+///     format!("Hello world with PhantomData<{}>", std::any::type_name::<DisplaylessType>())
+/// );
+/// assert_eq!(
+///     PhantomEnum::<DisplaylessType>::Variant2 { msg: "hi".into() }.to_string(),
+///     "Variant2 says       hi"
 /// );
 /// ```
 #[inline]
-#[proc_macro_derive(Ord, attributes(better_derive, ord))]
-pub fn ord(input: TokenStream) -> TokenStream { ord::ord(input) }
+#[proc_macro_derive(Display, attributes(better_derive, display))]
+pub fn display(input: TokenStream) -> TokenStream { display::display(input) }
 
-/// Defines a [`PartialEq`](derive@::std::cmp::PartialEq)-like derive macro that's more lenient to
-/// generics.
+/// Defines an [`Eq`](derive@::std::cmp::Eq)-like derive macro that's more lenient to generics.
 ///
 /// In particular, the default derive macro enforces that all _generics_ implement
-/// [`PartialEq`](std::cmp::PartialEq). This is, however, too strict. Instead, all that's needed is
-/// that the _fields_ implement it, which may or may not require the generics to do so.
+/// [`Eq`](std::cmp::Eq). This is, however, too strict. Instead, all that's needed is that the
+/// _fields_ implement it, which may or may not require the generics to do so.
 ///
 /// You can use this macro in exactly the same way as the builtin one.
 ///
-/// # Skipping fields
-/// You can optionally omit fields from the generated implementation with the
-/// `#[partial_eq(skip)]`-attribute:
-/// ```rust
-/// use better_derive::PartialEq;
-///
-/// #[derive(PartialEq)]
-/// struct Foo {
-///     // Something very interesting we want to compare
-///     dynamic_field: String,
-///     // Something very boring that never changes anyway
-///     #[partial_eq(skip)]
-///     static_field:  (),
-/// }
-/// ```
-/// Importantly, adding the skip-attribute **also removes that field's type from the trait
-/// bounds**. If the field has a tricky type to implement `PartialEq` for, or is very expensive to
-/// compare but will never vary between two instances, you can simply not do it by using this
-/// attribute.
-///
-///
-///
 /// # Examples
 /// ```rust
 /// use std::marker::PhantomData;
 ///
-/// use better_derive::PartialEq;
+/// use better_derive::{Eq, PartialEq};
 ///
-/// struct PartialEqlessType;
+/// struct EqlessType;
 ///
-/// #[derive(PartialEq)]
+/// #[derive(Eq, PartialEq)]
 /// struct PhantomStruct<T> {
 ///     _t: PhantomData<T>,
 /// }
 ///
 /// assert!(
-///     PhantomStruct::<PartialEqlessType> { _t: PhantomData }
-///         == PhantomStruct::<PartialEqlessType> { _t: PhantomData }
+///     PhantomStruct::<EqlessType> { _t: PhantomData }
+///         == PhantomStruct::<EqlessType> { _t: PhantomData }
 /// );
 /// ```
 #[inline]
-#[proc_macro_derive(PartialEq, attributes(better_derive, partial_eq))]
-pub fn partial_eq(input: TokenStream) -> TokenStream { partial_eq::partial_eq(input) }
+#[proc_macro_derive(Eq, attributes(better_derive, eq))]
+pub fn eq(input: TokenStream) -> TokenStream { eq::eq(input) }
 
-/// Defines a [`PartialOrd`](derive@::std::cmp::PartialOrd)-like derive macro that's more lenient
-/// to generics.
+/// Defines a derive macro for [`Error`](std::error::Error) that's lenient to generics.
 ///
-/// In particular, the default derive macro enforces that all _generics_ implement
-/// [`PartialOrd`](std::cmp::PartialOrd). This is, however, too strict. Instead, all that's needed
-/// is that the _fields_ implement it, which may or may not require the generics to do so.
+/// Rust doesn't have a builtin derive macro for `Error`. This macro implements it by generating a
+/// [`source()`](std::error::Error::source())-implementation that returns the field marked as the
+/// source of the error, if any. Note that the `Error`-trait also requires [`Debug`](std::fmt::Debug)
+/// and [`Display`](std::fmt::Display), which you can derive with [our macros](derive@Debug) as
+/// [well](derive@Display).
 ///
-/// You can use this macro in exactly the same way as the builtin one.
+/// Only the source fields are bound, on `Error + 'static`. As such, an error type like `Error<E>`
+/// that doesn't use `E` as source does not need `E` to implement `Error`.
 ///
-/// # Skipping fields
-/// You can optionally omit fields from the generated implementation with the
-/// `#[partial_ord(skip)]`-attribute:
+///
+/// # Field roles
+/// Fields can be given one of the following roles:
+/// - `#[source]` marks the field as the source of the error, to be returned by `source()`.
+/// - `#[from]` does the same, but also generates a [`From`](std::convert::From)-impl that converts
+///   from the field's type. This is only possible if all other fields in the struct or variant are
+///   marked with `#[backtrace]`.
+/// - `#[backtrace]` marks the field as a [`Backtrace`](std::backtrace::Backtrace), which is
+///   captured whenever the generated `From`-impl is used. Its type can be anything that converts
+///   from `Backtrace` (e.g., `Backtrace` itself or `Option<Backtrace>`).
+///
+///
+/// # Examples
 /// ```rust
-/// use better_derive::{PartialEq, PartialOrd};
+/// use std::backtrace::Backtrace;
+/// use std::error::Error as _;
+/// use std::marker::PhantomData;
 ///
-/// #[derive(PartialEq, PartialOrd)]
+/// use better_derive::{Debug, Display, Error};
+///
+/// struct ErrorlessType;
+///
+/// #[derive(Debug, Display, Error)]
+/// enum PhantomError<E> {
+///     #[display("I/O error")]
+///     Io(#[from] std::io::Error),
+///     #[display("Parse error")]
+///     Parse {
+///         #[source]
+///         err: std::num::ParseIntError,
+///         _e:  PhantomData<E>,
+///     },
+///     #[display("Other error")]
+///     Other {
+///         #[from]
+///         err: std::fmt::Error,
+///         #[backtrace]
+///         trace: Backtrace,
+///     },
+/// }
+///
+/// let err: PhantomError<ErrorlessType> = std::io::Error::other("Oh no!").into();
+/// assert_eq!(err.source().unwrap().to_string(), "Oh no!");
+/// let err: PhantomError<ErrorlessType> = std::fmt::Error.into();
+/// assert!(err.source().is_some());
+/// ```
+///
+/// Sources can also be boxed trait objects:
+/// ```rust
+/// use std::error::Error as _;
+///
+/// use better_derive::{Debug, Display, Error};
+///
+/// #[derive(Debug, Display, Error)]
+/// #[display("Failed to load config")]
+/// struct ConfigError {
+///     #[source]
+///     inner: Box<dyn std::error::Error + Send + Sync>,
+/// }
+///
+/// #[derive(Debug, Display, Error)]
+/// enum AppError {
+///     #[display("Plugin failed")]
+///     Plugin(#[from] Box<dyn std::error::Error>),
+/// }
+///
+/// let err = ConfigError { inner: "Oh no!".into() };
+/// assert_eq!(err.source().unwrap().to_string(), "Oh no!");
+/// let err: AppError = Box::<dyn std::error::Error>::from("Oh no!").into();
+/// assert_eq!(err.source().unwrap().to_string(), "Oh no!");
+/// ```
+#[inline]
+#[proc_macro_derive(Error, attributes(better_derive, error, source, from, backtrace))]
+pub fn error(input: TokenStream) -> TokenStream { error::error(input) }
+
+/// Defines a derive macro for [`Extend`](std::iter::Extend) that forwards to the field of a
+/// newtype.
+///
+/// This macro works the same as [`FromIterator`](derive@FromIterator), except that it implements
+/// `Extend<A>` for your newtype whenever its field implements `Extend<A>`.
+///
+/// # Examples
+/// ```rust
+/// use better_derive::Extend;
+///
+/// #[derive(Extend)]
+/// struct Names(Vec<String>);
+///
+/// let mut names = Names(vec!["Amy".into()]);
+/// names.extend(["Bob".to_string(), "Cho".to_string()]);
+/// assert_eq!(names.0, ["Amy", "Bob", "Cho"]);
+/// ```
+#[inline]
+#[proc_macro_derive(Extend, attributes(better_derive, extend))]
+pub fn extend(input: TokenStream) -> TokenStream { iter::extend(input) }
+
+/// Defines a derive macro for [`From`](std::convert::From) that's lenient to generics.
+///
+/// Rust doesn't have a builtin derive macro for `From`. This macro implements it for newtypes,
+/// i.e., structs with exactly one field, by converting from the type of that field. For enums, it
+/// generates a `From`-impl for the field type of every variant that has exactly one field.
+///
+/// Because the field is simply moved into the result, the generated impls don't bound any of the
+/// fields. As such, `From<PhantomData<T>>` is implemented for any `T`.
+///
+///
+/// # Skipping variants
+/// You can opt-out of the impl for a particular variant by marking it with `#[from(skip)]`. This
+/// is required when two variants have the same field type, as they would otherwise generate
+/// conflicting impls. The same holds when a variant's field is a bare type parameter, which may be
+/// the type of any other variant's field (unless that type uses the same parameter):
+/// ```compile_fail
+/// use better_derive::From;
+///
+/// #[derive(From)]
+/// enum Either<L, R> {
+///     // Either of these must be marked as `#[from(skip)]`
+///     Left(L),
+///     Right(R),
+/// }
+/// ```
+///
+///
+/// # Examples
+/// ```rust
+/// use std::marker::PhantomData;
+///
+/// use better_derive::From;
+///
+/// struct FromlessType;
+///
+/// #[derive(From)]
+/// struct PhantomStruct<T>(PhantomData<T>);
+///
+/// #[derive(From)]
+/// enum Value<T> {
+///     Int(i64),
+///     Str { value: String },
+///     #[from(skip)]
+///     Name(String),
+///     Phantom(PhantomData<T>),
+///     Pair(u8, u8),
+/// }
+///
+/// let _: PhantomStruct<FromlessType> = PhantomData.into();
+/// assert!(matches!(Value::<FromlessType>::from(42i64), Value::Int(42)));
+/// assert!(matches!(Value::<FromlessType>::from(String::from("Hello")), Value::Str { value } if value == "Hello"));
+/// ```
+#[inline]
+#[proc_macro_derive(From, attributes(better_derive, from))]
+pub fn from(input: TokenStream) -> TokenStream { from::from(input) }
+
+/// Defines a derive macro for [`FromIterator`](std::iter::FromIterator) that forwards to the field
+/// of a newtype.
+///
+/// Rust doesn't have a builtin derive macro for `FromIterator`. This macro implements
+/// `FromIterator<A>` for structs with exactly one field, for every `A` that the field's type can be
+/// collected from. As such, the field's type is bound on `FromIterator<A>` (instead of, e.g., the
+/// generics it is made of).
+///
+/// # Examples
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use better_derive::FromIterator;
+///
+/// #[derive(FromIterator)]
+/// struct Registry<K, V>(BTreeMap<K, V>);
+///
+/// let registry: Registry<&str, u32> = [("Amy", 42), ("Bob", 43)].into_iter().collect();
+/// assert_eq!(registry.0["Bob"], 43);
+/// ```
+#[inline]
+#[proc_macro_derive(FromIterator, attributes(better_derive, from_iterator))]
+pub fn from_iterator(input: TokenStream) -> TokenStream { iter::from_iterator(input) }
+
+/// Defines a derive macro for [`FromStr`](std::str::FromStr) on enums without fields.
+///
+/// Rust doesn't have a builtin derive macro for `FromStr`. This macro implements it by parsing the
+/// names of the variants of the enum. Next to that, it also generates a
+/// [`TryFrom<&str>`](std::convert::TryFrom)-impl that does the same, and an
+/// `as_str(&self) -> &'static str`-method that does the reverse.
+///
+/// If parsing fails, an error of a generated type `Parse<NAME>Error` is returned. It has the same
+/// visibility as the enum, and carries the string that failed to parse in its `raw`-field.
+///
+///
+/// # Names
+/// By default, variants are parsed from their exact identifier. You can change this using the
+/// following attributes:
+/// - `#[from_str(rename_all = "...")]` on the enum applies a case convention to all variants. It is
+///   one of `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`, `"snake_case"`,
+///   `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` or `"SCREAMING-KEBAB-CASE"`.
+/// - `#[from_str(case_insensitive)]` on the enum ignores the (ASCII) case of the string when parsing.
+/// - `#[from_str(rename = "...")]` on a variant gives it an explicit name instead.
+/// - `#[from_str(alias = "...")]` on a variant allows it to also be parsed from the given string.
+///   Aliases are never returned by `as_str()`.
+///
+///
+/// # Examples
+/// ```rust
+/// use std::str::FromStr as _;
+///
+/// use better_derive::FromStr;
+///
+/// #[derive(Debug, FromStr, PartialEq)]
+/// #[from_str(rename_all = "kebab-case", case_insensitive)]
+/// enum LogLevel {
+///     #[from_str(alias = "warn")]
+///     Warning,
+///     #[from_str(rename = "err")]
+///     Error,
+///     VeryVerbose,
+/// }
+///
+/// assert_eq!(LogLevel::from_str("very-verbose"), Ok(LogLevel::VeryVerbose));
+/// assert_eq!(LogLevel::from_str("WARN"), Ok(LogLevel::Warning));
+/// assert_eq!(LogLevel::try_from("Err"), Ok(LogLevel::Error));
+/// assert_eq!(LogLevel::Warning.as_str(), "warning");
+///
+/// let err = LogLevel::from_str("info").unwrap_err();
+/// assert_eq!(err.raw, "info");
+/// assert_eq!(err.to_string(), "Unknown LogLevel \"info\" (expected one of \"warning\", \"err\", \"very-verbose\")");
+/// ```
+#[inline]
+#[proc_macro_derive(FromStr, attributes(better_derive, from_str))]
+pub fn from_str(input: TokenStream) -> TokenStream { from_str::from_str(input) }
+
+/// Defines a [`Hash`](derive@::std::hash::Hash)-like derive macro that's more lenient to generics.
+///
+/// In particular, the default derive macro enforces that all _generics_ implement
+/// [`Hash`](std::cmp::Hash). This is, however, too strict. Instead, all that's needed is
+/// that the _fields_ implement it, which may or may not require the generics to do so.
+///
+/// You can use this macro in exactly the same way as the builtin one.
+///
+///
+/// # Skipping fields
+/// You can optionally omit fields from the generated implementation with the `#[hash(skip)]`-
+/// attribute:
+/// ```rust
+/// use better_derive::Hash;
+///
+/// #[derive(Hash)]
+/// struct Foo {
+///     // Something very interesting we want to hash
+///     dynamic_field: String,
+///     // Something very boring that never changes anyway
+///     #[hash(skip)]
+///     static_field:  (),
+/// }
+/// ```
+/// Importantly, adding the skip-attribute **also removes that field's type from the trait
+/// bounds**. If the field has a tricky type to implement `Hash` for, or is very expensive to hash
+/// but will never vary between two instances, you can simply not do it by using this attribute.
+///
+///
+/// # Examples
+/// ```rust
+/// use std::hash::{DefaultHasher, Hash as _, Hasher as _};
+/// use std::marker::PhantomData;
+///
+/// use better_derive::Hash;
+///
+/// struct HashlessType;
+///
+/// #[derive(Hash)]
+/// struct PhantomStruct<T> {
+///     _t: PhantomData<T>,
+/// }
+///
+/// assert_eq!(
+///     {
+///         let mut state = DefaultHasher::default();
+///         PhantomStruct::<HashlessType> { _t: PhantomData }.hash(&mut state);
+///         state.finish()
+///     },
+///     15130871412783076140
+/// );
+/// ```
+#[inline]
+#[proc_macro_derive(Hash, attributes(better_derive, hash))]
+pub fn hash(input: TokenStream) -> TokenStream { hash::hash(input) }
+
+/// Defines a derive macro for [`Index`](std::ops::Index) that forwards to a field.
+///
+/// Rust doesn't have a builtin derive macro for `Index`. This macro implements it by indexing the
+/// only field of a struct, or the field marked with `#[index]` if there are multiple. The index
+/// type is left generic, so your type can be indexed by anything its field can.
+///
+/// As such, the field's type is bound on `Index<Idx>` (instead of, e.g., the generics it is made
+/// of).
+///
+/// # Examples
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use better_derive::Index;
+///
+/// #[derive(Index)]
+/// struct Stack<T>(Vec<T>);
+///
+/// #[derive(Index)]
+/// struct Registry {
+///     name:  &'static str,
+///     #[index]
+///     users: HashMap<String, u32>,
+/// }
+///
+/// let stack = Stack(vec![1, 2, 3]);
+/// assert_eq!(stack[0], 1);
+/// assert_eq!(stack[1..], [2, 3]);
+///
+/// let registry = Registry { name: "users", users: HashMap::from([("Amy".to_string(), 42)]) };
+/// assert_eq!(registry["Amy"], 42);
+/// assert_eq!(registry.name, "users");
+/// ```
+#[inline]
+#[proc_macro_derive(Index, attributes(better_derive, index))]
+pub fn index(input: TokenStream) -> TokenStream { index::index(input) }
+
+/// Defines a derive macro for [`IndexMut`](std::ops::IndexMut) that forwards to a field.
+///
+/// This macro works exactly the same as [`Index`](derive@Index), and can only be used together
+/// with it (or with a manual `Index`-impl to the same field). The field's type is bound on
+/// `IndexMut<Idx>`.
+///
+/// # Examples
+/// ```rust
+/// use better_derive::{Index, IndexMut};
+///
+/// #[derive(Index, IndexMut)]
+/// struct Grid<T> {
+///     width: usize,
+///     #[index]
+///     cells: Vec<T>,
+/// }
+///
+/// let mut grid = Grid { width: 2, cells: vec![0; 4] };
+/// grid[3] = 42;
+/// grid[..2].fill(1);
+/// assert_eq!(grid.cells, [1, 1, 0, 42]);
+/// assert_eq!(grid.width, 2);
+/// ```
+#[inline]
+#[proc_macro_derive(IndexMut, attributes(better_derive, index, index_mut))]
+pub fn index_mut(input: TokenStream) -> TokenStream { index::index_mut(input) }
+
+/// Defines a derive macro for [`IntoIterator`](std::iter::IntoIterator) that forwards to the field
+/// of a newtype.
+///
+/// Rust doesn't have a builtin derive macro for `IntoIterator`. This macro implements it for
+/// structs with exactly one field, by iterating over that field. This is done for your type by
+/// value, by reference and by mutable reference. The latter two only apply if the field can be
+/// iterated by (mutable) reference, respectively.
+///
+/// The field's type is bound on `IntoIterator` (instead of, e.g., the generics it is made of).
+///
+/// # Examples
+/// ```rust
+/// use better_derive::IntoIterator;
+///
+/// #[derive(IntoIterator)]
+/// struct Names<T>(Vec<T>);
+///
+/// let mut names = Names(vec!["Amy".to_string(), "Bob".to_string()]);
+/// for name in &mut names {
+///     name.push('!');
+/// }
+/// assert_eq!((&names).into_iter().map(String::len).sum::<usize>(), 8);
+/// assert_eq!(names.into_iter().collect::<Vec<_>>(), ["Amy!", "Bob!"]);
+/// ```
+#[inline]
+#[proc_macro_derive(IntoIterator, attributes(better_derive, into_iterator))]
+pub fn into_iterator(input: TokenStream) -> TokenStream { iter::into_iterator(input) }
+
+/// Defines an [`Ord`](derive@::std::cmp::Ord)-like derive macro that's more lenient to generics.
+///
+/// In particular, the default derive macro enforces that all _generics_ implement
+/// [`Ord`](std::cmp::Ord). This is, however, too strict. Instead, all that's needed is that the
+/// _fields_ implement it, which may or may not require the generics to do so.
+///
+/// This macro will generate an implementation with the bounds described as above, but that will
+/// otherwise refer to the implementation of [`PartialOrd`](std::cmp::PartialOrd). As such, if
+/// you're deriving it, it is recommended to use [our derive macro](derive@PartialOrd) as well.
+///
+/// You can use this macro in exactly the same way as the builtin one.
+///
+/// # Skipping fields
+/// You can optionally omit fields from the generated implementation with the
+/// `#[ord(skip)]`-attribute:
+/// ```rust
+/// use better_derive::{Eq, Ord, PartialEq, PartialOrd};
+///
+/// #[derive(Ord, PartialEq, Eq, PartialOrd)]
 /// struct Foo {
 ///     // Something very interesting we want to compare
 ///     dynamic_field: String,
 ///     // Something very boring that never changes anyway
-///     #[partial_ord(skip)]
+///     #[ord(skip)]
 ///     static_field:  (),
 /// }
 /// ```
 /// Importantly, adding the skip-attribute **also removes that field's type from the trait
-/// bounds**. If the field has a tricky type to implement `PartialOrd` for, or is very expensive to
+/// bounds**. If the field has a tricky type to implement `Ord` for, or is very expensive to
 /// compare but will never vary between two instances, you can simply not do it by using this
 /// attribute.
 ///
@@ -499,25 +1350,1196 @@ pub fn partial_eq(input: TokenStream) -> TokenStream { partial_eq::partial_eq(in
 /// ```rust
 /// use std::marker::PhantomData;
 ///
-/// use better_derive::{PartialEq, PartialOrd};
+/// use better_derive::{Eq, Ord, PartialEq, PartialOrd};
 ///
-/// struct PartialOrdlessType;
+/// struct OrdlessType;
 ///
-/// #[derive(PartialEq, PartialOrd)]
+/// #[derive(PartialEq, Ord, Eq, PartialOrd)]
 /// struct PhantomStruct<T> {
 ///     _t: PhantomData<T>,
 /// }
 ///
 /// assert!(
-///     !(PhantomStruct::<PartialOrdlessType> { _t: PhantomData }
-///         > PhantomStruct::<PartialOrdlessType> { _t: PhantomData })
+///     !(PhantomStruct::<OrdlessType> { _t: PhantomData }
+///         > PhantomStruct::<OrdlessType> { _t: PhantomData })
 /// );
 /// ```
 #[inline]
-#[proc_macro_derive(PartialOrd, attributes(better_derive, partial_ord))]
-pub fn partial_ord(input: TokenStream) -> TokenStream { partial_ord::partial_ord(input) }
-
+#[proc_macro_derive(Ord, attributes(better_derive, ord))]
+pub fn ord(input: TokenStream) -> TokenStream { ord::ord(input) }
 
+/// Defines a [`PartialEq`](derive@::std::cmp::PartialEq)-like derive macro that's more lenient to
+/// generics.
+///
+/// In particular, the default derive macro enforces that all _generics_ implement
+/// [`PartialEq`](std::cmp::PartialEq). This is, however, too strict. Instead, all that's needed is
+/// that the _fields_ implement it, which may or may not require the generics to do so.
+///
+/// You can use this macro in exactly the same way as the builtin one.
+///
+/// # Skipping fields
+/// You can optionally omit fields from the generated implementation with the
+/// `#[partial_eq(skip)]`-attribute:
+/// ```rust
+/// use better_derive::PartialEq;
+///
+/// #[derive(PartialEq)]
+/// struct Foo {
+///     // Something very interesting we want to compare
+///     dynamic_field: String,
+///     // Something very boring that never changes anyway
+///     #[partial_eq(skip)]
+///     static_field:  (),
+/// }
+/// ```
+/// Importantly, adding the skip-attribute **also removes that field's type from the trait
+/// bounds**. If the field has a tricky type to implement `PartialEq` for, or is very expensive to
+/// compare but will never vary between two instances, you can simply not do it by using this
+/// attribute.
+///
+///
+///
+/// # Examples
+/// ```rust
+/// use std::marker::PhantomData;
+///
+/// use better_derive::PartialEq;
+///
+/// struct PartialEqlessType;
+///
+/// #[derive(PartialEq)]
+/// struct PhantomStruct<T> {
+///     _t: PhantomData<T>,
+/// }
+///
+/// assert!(
+///     PhantomStruct::<PartialEqlessType> { _t: PhantomData }
+///         == PhantomStruct::<PartialEqlessType> { _t: PhantomData }
+/// );
+/// ```
+#[inline]
+#[proc_macro_derive(PartialEq, attributes(better_derive, partial_eq))]
+pub fn partial_eq(input: TokenStream) -> TokenStream { partial_eq::partial_eq(input) }
+
+/// Defines a [`PartialOrd`](derive@::std::cmp::PartialOrd)-like derive macro that's more lenient
+/// to generics.
+///
+/// In particular, the default derive macro enforces that all _generics_ implement
+/// [`PartialOrd`](std::cmp::PartialOrd). This is, however, too strict. Instead, all that's needed
+/// is that the _fields_ implement it, which may or may not require the generics to do so.
+///
+/// You can use this macro in exactly the same way as the builtin one.
+///
+/// # Skipping fields
+/// You can optionally omit fields from the generated implementation with the
+/// `#[partial_ord(skip)]`-attribute:
+/// ```rust
+/// use better_derive::{PartialEq, PartialOrd};
+///
+/// #[derive(PartialEq, PartialOrd)]
+/// struct Foo {
+///     // Something very interesting we want to compare
+///     dynamic_field: String,
+///     // Something very boring that never changes anyway
+///     #[partial_ord(skip)]
+///     static_field:  (),
+/// }
+/// ```
+/// Importantly, adding the skip-attribute **also removes that field's type from the trait
+/// bounds**. If the field has a tricky type to implement `PartialOrd` for, or is very expensive to
+/// compare but will never vary between two instances, you can simply not do it by using this
+/// attribute.
+///
+///
+///
+/// # Examples
+/// ```rust
+/// use std::marker::PhantomData;
+///
+/// use better_derive::{PartialEq, PartialOrd};
+///
+/// struct PartialOrdlessType;
+///
+/// #[derive(PartialEq, PartialOrd)]
+/// struct PhantomStruct<T> {
+///     _t: PhantomData<T>,
+/// }
+///
+/// assert!(
+///     !(PhantomStruct::<PartialOrdlessType> { _t: PhantomData }
+///         > PhantomStruct::<PartialOrdlessType> { _t: PhantomData })
+/// );
+/// ```
+#[inline]
+#[proc_macro_derive(PartialOrd, attributes(better_derive, partial_ord))]
+pub fn partial_ord(input: TokenStream) -> TokenStream { partial_ord::partial_ord(input) }
+
+/// Defines a derive macro that extracts the fields of enum variants using
+/// [`TryFrom`](std::convert::TryFrom).
+///
+/// Specifically, for every variant with exactly one field, this macro generates
+/// `TryFrom<Enum> for Field` and `TryFrom<&'a Enum> for &'a Field`. As such, you can call
+/// `try_into()` on the enum (or a reference to it) to get the field of a particular variant. If
+/// the enum is of another variant, then the original value is handed back as the error.
+///
+/// Because the field is simply moved, the generated impls don't bound any of the fields.
+///
+///
+/// # Skipping variants
+/// You can opt-out of the impls for a particular variant by marking it with `#[try_into(skip)]`.
+/// This is required when two variants have the same field type, as they would otherwise generate
+/// conflicting impls. It is also required for variants whose field is a bare type parameter, as
+/// Rust doesn't allow implementing `TryFrom` for any `T`:
+/// ```compile_fail
+/// use better_derive::TryInto;
+///
+/// #[derive(TryInto)]
+/// enum Value<T> {
+///     // Must be marked as `#[try_into(skip)]`
+///     Custom(T),
+///     Int(i64),
+/// }
+/// ```
+///
+///
+/// # Examples
+/// ```rust
+/// use std::marker::PhantomData;
+///
+/// use better_derive::TryInto;
+///
+/// struct TryIntolessType;
+///
+/// #[derive(TryInto)]
+/// enum Value<T> {
+///     Int(i64),
+///     Str { value: String },
+///     #[try_into(skip)]
+///     Name(String),
+///     Phantom(PhantomData<T>),
+/// }
+///
+/// let value: Value<TryIntolessType> = Value::Int(42);
+/// assert_eq!(<&i64>::try_from(&value).ok(), Some(&42));
+/// assert!(<&String>::try_from(&value).is_err());
+/// assert_eq!(i64::try_from(value).ok(), Some(42));
+///
+/// let value: Value<TryIntolessType> = Value::Name("Hello".into());
+/// let value: Value<TryIntolessType> = match String::try_from(value) {
+///     Ok(_) => unreachable!(),
+///     Err(value) => value,
+/// };
+/// assert!(matches!(value, Value::Name(name) if name == "Hello"));
+/// ```
+#[inline]
+#[proc_macro_derive(TryInto, attributes(better_derive, try_into))]
+pub fn try_into(input: TokenStream) -> TokenStream { try_into::try_into(input) }
+
+/// Defines a derive macro that unsafely implements [`Send`](std::marker::Send) for types that
+/// don't get it automatically (e.g., because they hold raw pointers).
+///
+/// Every field is bound on `Send`, including the ones that don't use any generics. As such, types
+/// that aren't `Send` have to be exempted explicitly by marking their field with
+/// `#[send(assume)]`, which documents per field what is assumed to be safe.
+///
+/// # Safety
+/// By deriving this, you promise that the fields marked with `#[send(assume)]` can safely be sent
+/// to another thread, as the compiler won't check it.
+///
+/// # Examples
+/// ```rust
+/// use better_derive::{UnsafeSend, UnsafeSync};
+///
+/// /// Owns a buffer allocated by some C-library.
+/// #[derive(UnsafeSend, UnsafeSync)]
+/// struct Buffer<T> {
+///     /// SAFETY: The buffer is exclusively owned, so sending or sharing it is fine.
+///     #[send(assume)]
+///     #[sync(assume)]
+///     ptr:  *mut u8,
+///     len:  usize,
+///     meta: T,
+/// }
+///
+/// fn assert_send<T: Send>() {}
+/// fn assert_sync<T: Sync>() {}
+/// assert_send::<Buffer<String>>();
+/// assert_sync::<Buffer<String>>();
+/// ```
+///
+/// This works for unions too:
+/// ```rust
+/// use better_derive::UnsafeSend;
+///
+/// #[derive(UnsafeSend)]
+/// union Slot {
+///     /// SAFETY: The pointee is only ever accessed by the owner of the slot.
+///     #[send(assume)]
+///     ptr:   *mut u8,
+///     index: usize,
+/// }
+///
+/// fn assert_send<T: Send>() {}
+/// assert_send::<Slot>();
+/// ```
+///
+/// Fields that aren't `Send` must be assumed explicitly:
+/// ```compile_fail
+/// use better_derive::UnsafeSend;
+///
+/// #[derive(UnsafeSend)]
+/// struct Buffer {
+///     ptr: *mut u8,
+///     len: usize,
+/// }
+/// ```
+///
+/// And the generic ones are still bound:
+/// ```compile_fail
+/// use std::rc::Rc;
+///
+/// use better_derive::UnsafeSend;
+///
+/// #[derive(UnsafeSend)]
+/// struct Buffer<T> {
+///     #[send(assume)]
+///     ptr:  *mut u8,
+///     meta: T,
+/// }
+///
+/// fn assert_send<T: Send>() {}
+/// assert_send::<Buffer<Rc<()>>>();
+/// ```
+#[inline]
+#[proc_macro_derive(UnsafeSend, attributes(better_derive, send))]
+pub fn unsafe_send(input: TokenStream) -> TokenStream { unsafe_marker::unsafe_send(input) }
+
+/// Defines a derive macro that unsafely implements [`Sync`](std::marker::Sync) for types that
+/// don't get it automatically (e.g., because they hold raw pointers).
+///
+/// This works the same as [`UnsafeSend`](derive@UnsafeSend), except that fields are bound on
+/// `Sync` and exempted with `#[sync(assume)]`.
+///
+/// # Safety
+/// By deriving this, you promise that the fields marked with `#[sync(assume)]` can safely be
+/// shared between threads, as the compiler won't check it.
+///
+/// # Examples
+/// ```rust
+/// use std::cell::Cell;
+///
+/// use better_derive::UnsafeSync;
+///
+/// #[derive(UnsafeSync)]
+/// struct Counter {
+///     /// SAFETY: Only ever accessed while holding a global lock.
+///     #[sync(assume)]
+///     count: Cell<usize>,
+///     name:  String,
+/// }
+///
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<Counter>();
+/// ```
+#[inline]
+#[proc_macro_derive(UnsafeSync, attributes(better_derive, sync))]
+pub fn unsafe_sync(input: TokenStream) -> TokenStream { unsafe_marker::unsafe_sync(input) }
+
+
+
+
+
+/***** OPERATORS *****/
+/// Defines a derive macro for [`Add`](std::ops::Add) that works field-wise.
+///
+/// Rust doesn't have a builtin derive macro for `Add`. This macro implements it for structs by
+/// adding every field of the left-hand side to the same field of the right-hand side. As such, for
+/// newtypes, it simply forwards to the implementation of the inner type.
+///
+/// Every field type is bound on `Add<Output = FIELD>`, unless it doesn't depend on any generics.
+/// Fields of type `PhantomData` are not added, but simply created anew. As such, these impose no
+/// bounds at all.
+///
+///
+/// # Other operators
+/// The same holds for the other operator macros, which only differ in the operator they implement:
+/// - [`BitAnd`](derive@BitAnd), [`BitOr`](derive@BitOr), [`BitXor`](derive@BitXor),
+///   [`Div`](derive@Div), [`Mul`](derive@Mul), [`Rem`](derive@Rem) and [`Sub`](derive@Sub) work
+///   exactly like `Add`.
+/// - [`Shl`](derive@Shl) and [`Shr`](derive@Shr) work like `Add` too, which means that they shift
+///   by `Self` (i.e., every field is shifted by the same field of the right-hand side) instead of
+///   by some integer.
+/// - [`Neg`](derive@Neg) and [`Not`](derive@Not) implement the unary operators, which are applied
+///   to every field.
+/// - The `*Assign`-counterparts (e.g., [`AddAssign`](derive@AddAssign)) implement the compound
+///   assignment operators by assigning to every field in-place. Fields are bound on the
+///   `*Assign`-trait instead (e.g., `AddAssign`).
+///
+///
+/// # Examples
+/// ```rust
+/// use std::marker::PhantomData;
+///
+/// use better_derive::{Add, Debug, PartialEq};
+///
+/// struct Unitless;
+///
+/// #[derive(Debug, Add, PartialEq)]
+/// struct Meters<T>(T);
+///
+/// #[derive(Debug, Add, PartialEq)]
+/// struct Point<T, U> {
+///     x: T,
+///     y: T,
+///     _unit: PhantomData<U>,
+/// }
+///
+/// assert_eq!(Meters(1.5) + Meters(2.0), Meters(3.5));
+/// assert_eq!(
+///     Point::<i32, Unitless> { x: 1, y: 2, _unit: PhantomData } + Point { x: 3, y: 4, _unit: PhantomData },
+///     Point { x: 4, y: 6, _unit: PhantomData }
+/// );
+/// ```
+///
+/// The other operators work the same:
+/// ```rust
+/// use std::marker::PhantomData;
+///
+/// use better_derive::{Debug, Neg, PartialEq, Shl, SubAssign};
+///
+/// struct Unitless;
+///
+/// #[derive(Debug, Neg, PartialEq, Shl, SubAssign)]
+/// struct Pair<U>(i32, i32, PhantomData<U>);
+///
+/// assert_eq!(-Pair::<Unitless>(1, -2, PhantomData), Pair(-1, 2, PhantomData));
+/// assert_eq!(Pair::<Unitless>(1, 2, PhantomData) << Pair(3, 1, PhantomData), Pair(8, 4, PhantomData));
+///
+/// let mut pair = Pair::<Unitless>(5, 2, PhantomData);
+/// pair -= Pair(3, 4, PhantomData);
+/// assert_eq!(pair, Pair(2, -2, PhantomData));
+/// ```
+#[inline]
+#[proc_macro_derive(Add, attributes(better_derive, add))]
+pub fn add(input: TokenStream) -> TokenStream { ops::ops(Op::Add, input) }
+
+/// Defines a derive macro for [`AddAssign`](std::ops::AddAssign) that works field-wise.
+///
+/// This implements the `+=`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(AddAssign, attributes(better_derive, add_assign))]
+pub fn add_assign(input: TokenStream) -> TokenStream { ops::ops(Op::AddAssign, input) }
+
+/// Defines a derive macro for [`BitAnd`](std::ops::BitAnd) that works field-wise.
+///
+/// This implements the `&`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(BitAnd, attributes(better_derive, bitand))]
+pub fn bitand(input: TokenStream) -> TokenStream { ops::ops(Op::BitAnd, input) }
+
+/// Defines a derive macro for [`BitAndAssign`](std::ops::BitAndAssign) that works field-wise.
+///
+/// This implements the `&=`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(BitAndAssign, attributes(better_derive, bitand_assign))]
+pub fn bitand_assign(input: TokenStream) -> TokenStream { ops::ops(Op::BitAndAssign, input) }
+
+/// Defines a derive macro for [`BitOr`](std::ops::BitOr) that works field-wise.
+///
+/// This implements the `|`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(BitOr, attributes(better_derive, bitor))]
+pub fn bitor(input: TokenStream) -> TokenStream { ops::ops(Op::BitOr, input) }
+
+/// Defines a derive macro for [`BitOrAssign`](std::ops::BitOrAssign) that works field-wise.
+///
+/// This implements the `|=`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(BitOrAssign, attributes(better_derive, bitor_assign))]
+pub fn bitor_assign(input: TokenStream) -> TokenStream { ops::ops(Op::BitOrAssign, input) }
+
+/// Defines a derive macro for [`BitXor`](std::ops::BitXor) that works field-wise.
+///
+/// This implements the `^`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(BitXor, attributes(better_derive, bitxor))]
+pub fn bitxor(input: TokenStream) -> TokenStream { ops::ops(Op::BitXor, input) }
+
+/// Defines a derive macro for [`BitXorAssign`](std::ops::BitXorAssign) that works field-wise.
+///
+/// This implements the `^=`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(BitXorAssign, attributes(better_derive, bitxor_assign))]
+pub fn bitxor_assign(input: TokenStream) -> TokenStream { ops::ops(Op::BitXorAssign, input) }
+
+/// Defines a derive macro for [`Div`](std::ops::Div) that works field-wise.
+///
+/// This implements the `/`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(Div, attributes(better_derive, div))]
+pub fn div(input: TokenStream) -> TokenStream { ops::ops(Op::Div, input) }
+
+/// Defines a derive macro for [`DivAssign`](std::ops::DivAssign) that works field-wise.
+///
+/// This implements the `/=`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(DivAssign, attributes(better_derive, div_assign))]
+pub fn div_assign(input: TokenStream) -> TokenStream { ops::ops(Op::DivAssign, input) }
+
+/// Defines a derive macro for [`Mul`](std::ops::Mul) that works field-wise.
+///
+/// This implements the `*`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(Mul, attributes(better_derive, mul))]
+pub fn mul(input: TokenStream) -> TokenStream { ops::ops(Op::Mul, input) }
+
+/// Defines a derive macro for [`MulAssign`](std::ops::MulAssign) that works field-wise.
+///
+/// This implements the `*=`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(MulAssign, attributes(better_derive, mul_assign))]
+pub fn mul_assign(input: TokenStream) -> TokenStream { ops::ops(Op::MulAssign, input) }
+
+/// Defines a derive macro for [`Neg`](std::ops::Neg) that works field-wise.
+///
+/// This implements the unary `-`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(Neg, attributes(better_derive, neg))]
+pub fn neg(input: TokenStream) -> TokenStream { ops::ops(Op::Neg, input) }
+
+/// Defines a derive macro for [`Not`](std::ops::Not) that works field-wise.
+///
+/// This implements the unary `!`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(Not, attributes(better_derive, not))]
+pub fn not(input: TokenStream) -> TokenStream { ops::ops(Op::Not, input) }
+
+/// Defines a derive macro for [`Rem`](std::ops::Rem) that works field-wise.
+///
+/// This implements the `%`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(Rem, attributes(better_derive, rem))]
+pub fn rem(input: TokenStream) -> TokenStream { ops::ops(Op::Rem, input) }
+
+/// Defines a derive macro for [`RemAssign`](std::ops::RemAssign) that works field-wise.
+///
+/// This implements the `%=`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(RemAssign, attributes(better_derive, rem_assign))]
+pub fn rem_assign(input: TokenStream) -> TokenStream { ops::ops(Op::RemAssign, input) }
+
+/// Defines a derive macro for [`Shl`](std::ops::Shl) that works field-wise.
+///
+/// This implements the `<<`-operator (by `Self`). See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(Shl, attributes(better_derive, shl))]
+pub fn shl(input: TokenStream) -> TokenStream { ops::ops(Op::Shl, input) }
+
+/// Defines a derive macro for [`ShlAssign`](std::ops::ShlAssign) that works field-wise.
+///
+/// This implements the `<<=`-operator (by `Self`). See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(ShlAssign, attributes(better_derive, shl_assign))]
+pub fn shl_assign(input: TokenStream) -> TokenStream { ops::ops(Op::ShlAssign, input) }
+
+/// Defines a derive macro for [`Shr`](std::ops::Shr) that works field-wise.
+///
+/// This implements the `>>`-operator (by `Self`). See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(Shr, attributes(better_derive, shr))]
+pub fn shr(input: TokenStream) -> TokenStream { ops::ops(Op::Shr, input) }
+
+/// Defines a derive macro for [`ShrAssign`](std::ops::ShrAssign) that works field-wise.
+///
+/// This implements the `>>=`-operator (by `Self`). See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(ShrAssign, attributes(better_derive, shr_assign))]
+pub fn shr_assign(input: TokenStream) -> TokenStream { ops::ops(Op::ShrAssign, input) }
+
+/// Defines a derive macro for [`Sub`](std::ops::Sub) that works field-wise.
+///
+/// This implements the `-`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(Sub, attributes(better_derive, sub))]
+pub fn sub(input: TokenStream) -> TokenStream { ops::ops(Op::Sub, input) }
+
+/// Defines a derive macro for [`SubAssign`](std::ops::SubAssign) that works field-wise.
+///
+/// This implements the `-=`-operator. See [`Add`](derive@Add#other-operators) for details.
+#[inline]
+#[proc_macro_derive(SubAssign, attributes(better_derive, sub_assign))]
+pub fn sub_assign(input: TokenStream) -> TokenStream { ops::ops(Op::SubAssign, input) }
+
+
+
+
+/***** METHODS *****/
+/// Defines a derive macro that generates accessors for every field.
+///
+/// For every field `foo`, this generates:
+/// - `foo(&self) -> &Foo`, which returns a reference to the field;
+/// - `foo_mut(&mut self) -> &mut Foo`, which returns a mutable reference to it;
+/// - `set_foo(&mut self, value: Foo)`, which replaces it; and
+/// - `with_foo(self, value: Foo) -> Self`, which replaces it builder-style.
+///
+/// For tuple structs, the accessors are named after the field's index instead (e.g., `get_0()`,
+/// `get_0_mut()`, `set_0()` and `with_0()`).
+///
+/// Fields can be configured with the following attributes:
+/// - `#[accessors(skip)]` doesn't generate any accessors for the field;
+/// - `#[accessors(copy)]` makes the getter return the field by value instead; and
+/// - `#[accessors(vis = "...")]` gives the accessors another visibility than `pub` (e.g.,
+///   `#[accessors(vis = "pub(crate)")]`).
+///
+/// # Examples
+/// ```rust
+/// use better_derive::Accessors;
+///
+/// #[derive(Accessors)]
+/// struct User<T> {
+///     name:   String,
+///     #[accessors(copy)]
+///     age:    u32,
+///     #[accessors(vis = "pub(crate)")]
+///     data:   T,
+///     #[accessors(skip)]
+///     secret: &'static str,
+/// }
+///
+/// #[derive(Accessors)]
+/// struct Meters(f64);
+///
+/// let mut user = User { name: "Amy".into(), age: 42, data: (), secret: "hunter2" }.with_age(43);
+/// user.name_mut().push('!');
+/// user.set_data(());
+/// assert_eq!(user.name(), "Amy!");
+/// assert_eq!(user.age(), 43);
+/// assert_eq!(user.secret, "hunter2");
+///
+/// let mut meters = Meters(1.0);
+/// meters.set_0(2.0);
+/// assert_eq!(*meters.get_0(), 2.0);
+/// ```
+#[inline]
+#[proc_macro_derive(Accessors, attributes(better_derive, accessors))]
+pub fn accessors(input: TokenStream) -> TokenStream { accessors::accessors(input) }
+
+/// Defines a derive macro that generates a builder.
+///
+/// For a struct `Foo`, this generates a `FooBuilder` with a chainable setter for every field, and
+/// a `build()`-method that returns the `Foo` once all required fields are set. If any aren't, a
+/// generated `FooBuilderError` is returned instead, which names the missing fields. The builder
+/// can be obtained using `Foo::builder()`, `FooBuilder::new()` or `FooBuilder::default()`.
+///
+/// Fields can be configured with the following attributes:
+/// - `#[builder(default)]` makes the field optional, falling back to its type's [`Default`]-impl;
+/// - `#[builder(into)]` makes its setter accept anything that converts [`Into`] the field's type;
+///   and
+/// - `#[builder(skip)]` doesn't generate a setter at all, always using the [`Default`]-impl.
+///
+/// The builder has the same generics as your type, but only the types of fields that are
+/// initialized by default are bound, on [`Default`]. As such, a skipped `PhantomData<T>`-field
+/// doesn't require anything of `T`.
+///
+/// # Examples
+/// ```rust
+/// use std::marker::PhantomData;
+///
+/// use better_derive::Builder;
+///
+/// struct Tag;
+///
+/// #[derive(Builder)]
+/// struct Request<T> {
+///     #[builder(into)]
+///     url:     String,
+///     method:  &'static str,
+///     #[builder(default)]
+///     retries: u32,
+///     #[builder(skip)]
+///     _tag:    PhantomData<T>,
+/// }
+///
+/// let req = Request::<Tag>::builder().url("https://example.com").method("GET").build().unwrap();
+/// assert_eq!(req.url, "https://example.com");
+/// assert_eq!(req.method, "GET");
+/// assert_eq!(req.retries, 0);
+///
+/// let err = RequestBuilder::<Tag>::new().retries(3).build().err().unwrap();
+/// assert_eq!(err.missing, ["url", "method"]);
+/// assert_eq!(err.to_string(), "Cannot build Request without a value for field(s) url, method");
+/// ```
+#[inline]
+#[proc_macro_derive(Builder, attributes(better_derive, builder))]
+pub fn builder(input: TokenStream) -> TokenStream { builder::builder(input) }
+
+/// Defines a derive macro that reports in which fields two values differ.
+///
+/// Specifically, this generates a
+/// `fn diff<'a>(&'a self, other: &'a Self) -> Vec<(String, &'a dyn Debug, &'a dyn Debug)>` that
+/// compares every field of both values. For every field that isn't equal, it returns the path to
+/// that field (e.g., `inner.0`) together with both values. This is much more readable than two
+/// `Debug`-dumps of large structs.
+///
+/// Fields marked with `#[diff(nested)]` are recursed into, and must have a type that derives
+/// `Diff` as well. Fields can be left out using `#[diff(skip)]`, and fields skipped by
+/// [`PartialEq`](derive@PartialEq) (i.e., `#[partial_eq(skip)]`) are left out too. For enums, if
+/// the two values are different variants, the names of those are reported instead.
+///
+/// Every field that is compared directly is bound on [`PartialEq`](std::cmp::PartialEq) and
+/// [`Debug`](std::fmt::Debug). For nested fields, the type parameters they use (e.g., `T` in
+/// `Inner<T>`) are bound on those instead. Give a custom bound (e.g., `#[diff(bound = (...))]`) if
+/// the nested type needs something else.
+///
+/// # Examples
+/// ```rust
+/// use better_derive::Diff;
+///
+/// #[derive(Diff)]
+/// struct Point<T>(T, T);
+///
+/// #[derive(Diff)]
+/// struct Shape<T> {
+///     name:   &'static str,
+///     #[diff(nested)]
+///     origin: Point<T>,
+///     #[diff(skip)]
+///     id:     u64,
+/// }
+///
+/// #[derive(Diff)]
+/// enum Fill {
+///     Solid(u32),
+///     None,
+/// }
+///
+/// let left = Shape { name: "square", origin: Point(1, 2), id: 1 };
+/// let right = Shape { name: "square", origin: Point(1, 3), id: 2 };
+/// let diffs: Vec<String> = left.diff(&right).into_iter().map(|(path, l, r)| format!("{path}: {l:?} != {r:?}")).collect();
+/// assert_eq!(diffs, ["origin.1: 2 != 3"]);
+/// assert!(left.diff(&left).is_empty());
+///
+/// let diffs: Vec<String> = Fill::Solid(0xFF0000).diff(&Fill::None).into_iter().map(|(_, l, r)| format!("{l:?} != {r:?}")).collect();
+/// assert_eq!(diffs, ["Solid != None"]);
+/// ```
+#[inline]
+#[proc_macro_derive(Diff, attributes(better_derive, diff, partial_eq))]
+pub fn diff(input: TokenStream) -> TokenStream { diff::diff(input) }
+
+/// Defines a derive macro that generates metadata about the fields of a struct.
+///
+/// Specifically, this generates:
+/// - `const FIELD_NAMES: &[&str]`, which lists the names of all fields (or their indices, for
+///   tuple structs);
+/// - `const FIELD_TYPES: &[&str]`, which lists their types as written in the struct; and
+/// - `fn for_each_field(&self, f: &mut dyn FnMut(&'static str, &dyn Debug))`, which calls `f`
+///   with the name and value of every field.
+///
+/// All of these are in declaration order. Fields can be left out using `#[fields(skip)]` (or
+/// `#[better_derive(skip)]`). Only `for_each_field()` bounds the field types, on
+/// [`Debug`](std::fmt::Debug).
+///
+/// # Examples
+/// ```rust
+/// use better_derive::Fields;
+///
+/// #[derive(Fields)]
+/// struct Config<T> {
+///     name:     String,
+///     retries:  Option<T>,
+///     #[fields(skip)]
+///     password: String,
+/// }
+///
+/// assert_eq!(Config::<u32>::FIELD_NAMES, ["name", "retries"]);
+/// assert_eq!(Config::<u32>::FIELD_TYPES, ["String", "Option < T >"]);
+///
+/// let config = Config { name: "prod".into(), retries: Some(3), password: "hunter2".into() };
+/// let mut lines: Vec<String> = Vec::new();
+/// config.for_each_field(&mut |name, value| lines.push(format!("{name}={value:?}")));
+/// assert_eq!(lines, ["name=\"prod\"", "retries=Some(3)"]);
+/// # let _ = config.password;
+/// ```
+#[inline]
+#[proc_macro_derive(Fields, attributes(better_derive, fields))]
+pub fn fields(input: TokenStream) -> TokenStream { fields::fields(input) }
+
+/// Defines a derive macro that generates a fieldless mirror of an enum.
+///
+/// For an enum `Foo`, this generates an enum `FooKind` that has the same variants, but without any
+/// of their fields. Then, `Foo::kind()` returns which of these variants a value is. This is useful
+/// for, e.g., dispatch tables or metrics labels.
+///
+/// The kind enum derives the standard library's `Clone`, `Copy`, `Debug`, `Eq`, `Hash`, `Ord`,
+/// `PartialEq` and `PartialOrd`, which means it can be used as a map key. Additional attributes can
+/// be forwarded to it using `#[kind(attr(...))]`, both on the enum itself and on its variants.
+///
+/// # Examples
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use better_derive::Kind;
+///
+/// #[derive(Kind)]
+/// #[kind(attr(doc = "The kind of a request."))]
+/// enum Request<T> {
+///     Get(String),
+///     Put { key: String, value: T },
+///     #[kind(attr(allow(dead_code)))]
+///     Ping,
+/// }
+///
+/// let mut counts: HashMap<RequestKind, usize> = HashMap::new();
+/// for req in [Request::Get("a".into()), Request::Put { key: "b".into(), value: 42 }, Request::Get("c".into())] {
+///     *counts.entry(req.kind()).or_default() += 1;
+/// }
+/// assert_eq!(counts[&RequestKind::Get], 2);
+/// assert_eq!(counts[&RequestKind::Put], 1);
+/// assert_eq!(format!("{:?}", RequestKind::Put), "Put");
+/// assert!(RequestKind::Get < RequestKind::Ping);
+/// ```
+#[inline]
+#[proc_macro_derive(Kind, attributes(better_derive, kind))]
+pub fn kind(input: TokenStream) -> TokenStream { kind::kind(input) }
+
+/// Defines a derive macro that generates constructors.
+///
+/// For structs, this generates a `pub fn new(...) -> Self` that takes every field as a parameter,
+/// in the order they are declared. Unnamed fields are given as `field0`, `field1`, etc. For enums,
+/// it generates such a constructor for every variant instead, named after it in `snake_case`
+/// (e.g., `http_request()` for `HTTPRequest`). Names that are keywords are escaped (e.g.,
+/// `r#type()` for `Type`).
+///
+/// Fields can be left out of the parameters with the following attributes:
+/// - `#[new(default)]` initializes the field using its type's [`Default`]-impl; and
+/// - `#[new(value = ...)]` initializes the field with the given expression.
+///
+/// Give `#[new(const)]` on the type to generate `pub const fn`s instead. Then,
+/// `#[new(default)]` cannot be used, as `Default::default()` isn't `const`.
+///
+/// Only the types of fields marked with `#[new(default)]` are bound, on [`Default`].
+///
+/// # Examples
+/// ```rust
+/// use better_derive::New;
+///
+/// #[derive(New)]
+/// struct User {
+///     name:   String,
+///     #[new(default)]
+///     groups: Vec<String>,
+///     #[new(value = true)]
+///     active: bool,
+/// }
+///
+/// #[derive(New)]
+/// #[new(const)]
+/// struct Point(i32, i32);
+///
+/// #[derive(Debug, New, PartialEq)]
+/// enum Shape<T> {
+///     Circle { radius: T },
+///     Rectangle(T, T),
+///     Empty,
+/// }
+///
+/// let user = User::new("Amy".into());
+/// assert_eq!(user.name, "Amy");
+/// assert!(user.groups.is_empty());
+/// assert!(user.active);
+///
+/// const ORIGIN: Point = Point::new(0, 0);
+/// assert_eq!((ORIGIN.0, ORIGIN.1), (0, 0));
+///
+/// assert_eq!(Shape::circle(1.0), Shape::Circle { radius: 1.0 });
+/// assert_eq!(Shape::rectangle(1, 2), Shape::Rectangle(1, 2));
+/// assert_eq!(Shape::<u8>::empty(), Shape::Empty);
+///
+/// #[derive(Debug, New, PartialEq)]
+/// enum Token {
+///     Type,
+///     Struct(u32),
+///     HTTPHeader(String),
+/// }
+///
+/// assert_eq!(Token::r#type(), Token::Type);
+/// assert_eq!(Token::r#struct(42), Token::Struct(42));
+/// assert_eq!(Token::http_header("Host".into()), Token::HTTPHeader("Host".into()));
+/// ```
+///
+/// Fields initialized by default can't be used in a `const fn`:
+/// ```compile_fail
+/// use better_derive::New;
+///
+/// #[derive(New)]
+/// #[new(const)]
+/// struct Counter {
+///     #[new(default)]
+///     count: usize,
+/// }
+/// ```
+///
+/// Neither can a field be initialized in multiple ways:
+/// ```compile_fail
+/// use better_derive::New;
+///
+/// #[derive(New)]
+/// struct Counter {
+///     #[new(default)]
+///     #[new(value = 1)]
+///     count: usize,
+/// }
+/// ```
+#[inline]
+#[proc_macro_derive(New, attributes(better_derive, new))]
+pub fn new(input: TokenStream) -> TokenStream { new::new(input) }
+
+
+
+/// Defines a derive macro that generates a companion struct where every field is optional, for
+/// layering configuration.
+///
+/// For a struct `Foo`, this generates a `FooPatch` with the same generics and fields, but with
+/// every field wrapped in an [`Option`]. Fields marked with `#[patch(nested)]` use the patch of
+/// their own type instead (e.g., `BarPatch<T>` for a `Bar<T>`), which must then also derive
+/// `Patch`. Then, it generates:
+/// - `Foo::apply(&mut self, patch: FooPatch)`, which overwrites the fields set in the patch; and
+/// - `FooPatch::merge(self, other: FooPatch) -> FooPatch`, which layers two patches, where the
+///   fields set in `other` take precedence.
+///
+/// The patch always implements [`Default`] as the empty patch, which doesn't require anything of
+/// the fields' types. Anything else must be opted into by forwarding attributes to it using
+/// `#[patch(attr(...))]` (e.g., `#[patch(attr(derive(Clone, Debug)))]`), both on the struct itself
+/// and on its fields.
+///
+/// # Examples
+/// ```rust
+/// use better_derive::Patch;
+///
+/// #[derive(Patch)]
+/// struct Config<T> {
+///     name:    String,
+///     retries: T,
+///     #[patch(nested)]
+///     server:  Server,
+/// }
+///
+/// #[derive(Patch)]
+/// #[patch(attr(derive(Clone, Debug, PartialEq)))]
+/// struct Server {
+///     host:  String,
+///     port:  u16,
+///     ratio: f64,
+/// }
+///
+/// let mut config = Config { name: "app".into(), retries: 3, server: Server { host: "localhost".into(), port: 80, ratio: 0.5 } };
+///
+/// // Layer the patches, e.g., from a file and then from the environment
+/// let file = ConfigPatch { retries: Some(5), server: ServerPatch { port: Some(8080), ..Default::default() }, ..Default::default() };
+/// let env = ConfigPatch { retries: Some(1), ..Default::default() };
+/// let patch = file.merge(env);
+/// assert_eq!(patch.retries, Some(1));
+/// assert_eq!(patch.server, ServerPatch { host: None, port: Some(8080), ratio: None });
+///
+/// config.apply(patch);
+/// assert_eq!(config.name, "app");
+/// assert_eq!(config.retries, 1);
+/// assert_eq!(config.server.host, "localhost");
+/// assert_eq!(config.server.port, 8080);
+/// ```
+#[inline]
+#[proc_macro_derive(Patch, attributes(better_derive, patch))]
+pub fn patch(input: TokenStream) -> TokenStream { patch::patch(input) }
+
+
+
+/// Defines a derive macro that generates a pin-projection for a struct, e.g., for implementing
+/// futures and streams by hand.
+///
+/// For a struct `Foo`, this generates a `FooProj<'__pin>` with the same generics and fields, but
+/// where every field is a reference into a pinned `Foo`. Fields marked with `#[pin]` are projected
+/// to a `Pin<&'__pin mut F>`, and any others to an ordinary `&'__pin mut F`. It's obtained using
+/// `Foo::project(self: Pin<&mut Self>)`.
+///
+/// To guarantee that pinned fields stay pinned, this also:
+/// - implements [`Unpin`](std::marker::Unpin) for `Foo`, which is bound only on the types of the
+///   pinned fields (unlike the automatic impl, which is bound on all of them); and
+/// - prevents implementing [`Drop`](std::ops::Drop) for `Foo`, as that would allow moving the
+///   pinned fields.
+///
+/// Like with the other macros, the fields are only bound if they use any of the generics. Pinned
+/// fields that don't are still taken into account, though, so e.g. a
+/// [`PhantomPinned`](std::marker::PhantomPinned) makes the struct `!Unpin`.
+///
+/// # Examples
+/// ```rust
+/// use std::future::Future;
+/// use std::pin::Pin;
+/// use std::task::{Context, Poll};
+///
+/// use better_derive::PinProject;
+///
+/// /// Counts how often the inner future is polled.
+/// #[derive(PinProject)]
+/// struct CountPolls<F> {
+///     #[pin]
+///     inner: F,
+///     polls: usize,
+/// }
+/// impl<F: Future> Future for CountPolls<F> {
+///     type Output = (F::Output, usize);
+///
+///     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+///         let this = self.project();
+///         *this.polls += 1;
+///         match this.inner.poll(cx) {
+///             Poll::Ready(output) => Poll::Ready((output, *this.polls)),
+///             Poll::Pending => Poll::Pending,
+///         }
+///     }
+/// }
+///
+/// fn assert_unpin<T: Unpin>() {}
+/// // `String` isn't pinned, so it doesn't matter whether it's `Unpin`
+/// assert_unpin::<CountPolls<std::future::Ready<String>>>();
+///
+/// let mut fut = std::pin::pin!(CountPolls { inner: std::future::ready(42), polls: 0 });
+/// let mut cx = Context::from_waker(std::task::Waker::noop());
+/// assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready((42, 1)));
+/// ```
+///
+/// Pinned fields that aren't `Unpin` make the struct `!Unpin`:
+/// ```compile_fail
+/// use std::marker::PhantomPinned;
+///
+/// use better_derive::PinProject;
+///
+/// #[derive(PinProject)]
+/// struct SelfReferential {
+///     #[pin]
+///     _pinned: PhantomPinned,
+///     data:    [u8; 16],
+/// }
+///
+/// fn assert_unpin<T: Unpin>() {}
+/// assert_unpin::<SelfReferential>();
+/// ```
+///
+/// And the struct can't implement `Drop`:
+/// ```compile_fail
+/// use better_derive::PinProject;
+///
+/// #[derive(PinProject)]
+/// struct Guarded<F> {
+///     #[pin]
+///     inner: F,
+/// }
+/// impl<F> Drop for Guarded<F> {
+///     fn drop(&mut self) {}
+/// }
+/// ```
+#[inline]
+#[proc_macro_derive(PinProject, attributes(better_derive, pin_project, pin))]
+pub fn pin_project(input: TokenStream) -> TokenStream { pin_project::pin_project(input) }
+
+
+
+/// Defines a derive macro that computes a fingerprint of the shape of a type at compile time.
+///
+/// This generates a `const SHAPE_HASH: u64`, which is computed from the name of the type, the
+/// names of its fields and variants, the types of its fields (as written) and its explicit
+/// discriminants. As such, it changes whenever any of those change, which makes it suitable to
+/// invalidate cached or serialized values. It's computed using FNV-1a over the tokens of the
+/// type, so it doesn't depend on the compiler version or platform, nor on how the type is
+/// formatted. Note, however, that types are compared as written, so, e.g., replacing a type with
+/// an alias of it changes the fingerprint too.
+///
+/// Fields marked with `#[shape_hash(nested)]` also mix in the `SHAPE_HASH` of their type, which
+/// must then also derive `ShapeHash`. This way, changes to nested types are detected as well.
+///
+/// Fields and variants marked with `#[shape_hash(skip)]` (or `#[serialize(skip)]`) don't affect
+/// the fingerprint.
+///
+/// # Examples
+/// ```rust
+/// mod v1 {
+///     use better_derive::ShapeHash;
+///
+///     #[derive(ShapeHash)]
+///     pub struct Entry {
+///         pub key:   String,
+///         #[shape_hash(nested)]
+///         pub value: Value,
+///     }
+///
+///     #[derive(ShapeHash)]
+///     pub enum Value {
+///         Int(i64),
+///         Text(String),
+///     }
+/// }
+///
+/// mod v2 {
+///     use better_derive::ShapeHash;
+///
+///     #[derive(ShapeHash)]
+///     pub struct Entry {
+///         pub key:   String,
+///         #[shape_hash(nested)]
+///         pub value: Value,
+///         #[shape_hash(skip)]
+///         pub hits:  usize,
+///     }
+///
+///     #[derive(ShapeHash)]
+///     pub enum Value {
+///         Int(i64),
+///         Text(String),
+///         Bool(bool),
+///     }
+/// }
+///
+/// mod v3 {
+///     use better_derive::ShapeHash;
+///
+///     #[derive(ShapeHash)]
+///     pub struct Entry {
+///         pub key:   String,
+///         #[shape_hash(nested)]
+///         pub value: Value,
+///     }
+///
+///     #[derive(ShapeHash)]
+///     pub enum Value {
+///         Int(i64),
+///         Text(String),
+///         #[shape_hash(skip)]
+///         Bool(bool),
+///     }
+/// }
+///
+/// // Changing a nested type changes the fingerprint, but skipped fields and variants don't
+/// assert_ne!(v1::Entry::SHAPE_HASH, v2::Entry::SHAPE_HASH);
+/// assert_eq!(v1::Entry::SHAPE_HASH, v3::Entry::SHAPE_HASH);
+/// ```
+#[inline]
+#[proc_macro_derive(ShapeHash, attributes(better_derive, shape_hash, serialize))]
+pub fn shape_hash(input: TokenStream) -> TokenStream { shape_hash::shape_hash(input) }
+
+
+
+/// Defines a derive macro that generates predicates and projections for every variant of an enum.
+///
+/// For every variant `Foo`, this generates an `is_foo(&self) -> bool` that checks whether the
+/// value is of that variant. For variants with fields, it also generates:
+/// - `as_foo(&self) -> Option<&T>`, which returns a reference to its field;
+/// - `as_foo_mut(&mut self) -> Option<&mut T>`, which returns a mutable reference to it;
+/// - `into_foo(self) -> Result<T, Self>`, which returns it by value (or the original value if
+///   it's another variant); and
+/// - `unwrap_foo(self) -> T`, which returns it by value and panics if it's another variant.
+///
+/// For variants with multiple fields, these return a tuple of (references to) the fields instead
+/// (e.g., `Option<(&A, &B)>`). The method names are derived from the variant names in
+/// `snake_case`. Variants can opt-out using `#[variant_helpers(skip)]`.
+///
+/// # Examples
+/// ```rust
+/// use better_derive::VariantHelpers;
+///
+/// #[derive(VariantHelpers)]
+/// enum Message<T> {
+///     Ping,
+///     Data(T),
+///     KeyValue { key: String, value: T },
+/// }
+///
+/// let mut msg = Message::Data(vec![1, 2]);
+/// assert!(msg.is_data());
+/// assert!(!msg.is_ping());
+/// msg.as_data_mut().unwrap().push(3);
+/// assert_eq!(msg.as_data(), Some(&vec![1, 2, 3]));
+/// assert!(msg.as_key_value().is_none());
+///
+/// let msg = Message::KeyValue { key: "answer".into(), value: 42 };
+/// assert_eq!(msg.as_key_value(), Some((&"answer".to_string(), &42)));
+/// let msg = msg.into_data().unwrap_err();
+/// assert_eq!(msg.unwrap_key_value(), ("answer".to_string(), 42));
+/// ```
+///
+/// The panic of the `unwrap_*()`-methods names the actual variant:
+/// ```should_panic
+/// # use better_derive::VariantHelpers;
+/// #
+/// # #[derive(VariantHelpers)]
+/// # enum Message {
+/// #     Ping,
+/// #     Data(u32),
+/// # }
+/// #
+/// Message::Ping.unwrap_data();
+/// ```
+#[inline]
+#[proc_macro_derive(VariantHelpers, attributes(better_derive, variant_helpers))]
+pub fn variant_helpers(input: TokenStream) -> TokenStream { variant_helpers::variant_helpers(input) }
+
+/// Defines a derive macro that generates metadata about the variants of an enum.
+///
+/// Specifically, this generates:
+/// - `const VARIANT_NAMES: &[&str]`, which lists the names of all variants;
+/// - `const VARIANT_COUNT: usize`, which is the number of variants in `VARIANT_NAMES`; and
+/// - `const fn variant_name(&self) -> &'static str`, which returns the name of a value's variant.
+///
+/// For enums without fields, it also generates:
+/// - `const ALL: &[Self]`, which lists all variants;
+/// - `fn iter()`, which iterates over all variants; and
+/// - `const fn next(&self) -> Self` and `const fn prev(&self) -> Self`, which return the variant
+///   declared after or before it, respectively, starting over at the end.
+///
+/// All of these are in declaration order. The names are the same as those printed by our
+/// [`Debug`](derive@Debug) derive macro for the variant (i.e., `Enum::Variant`). Variants can be
+/// given another name using `#[variant_meta(rename = "...")]`, or left out of the lists using
+/// `#[variant_meta(skip)]`. Skipped variants are thus not counted by `VARIANT_COUNT` either, but
+/// `variant_name()` still names them.
+///
+/// # Examples
+/// ```rust
+/// use better_derive::VariantMeta;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, VariantMeta)]
+/// enum Weekday {
+///     Monday,
+///     Tuesday,
+///     #[variant_meta(rename = "Hump day")]
+///     Wednesday,
+///     Thursday,
+///     Friday,
+///     #[variant_meta(skip)]
+///     Holiday,
+/// }
+///
+/// #[derive(better_derive::Debug, VariantMeta)]
+/// enum Event<T> {
+///     Started,
+///     Data(T),
+/// }
+///
+/// assert_eq!(Weekday::VARIANT_COUNT, 5);
+/// assert_eq!(Weekday::VARIANT_NAMES, ["Weekday::Monday", "Weekday::Tuesday", "Hump day", "Weekday::Thursday", "Weekday::Friday"]);
+/// assert_eq!(Weekday::Wednesday.variant_name(), "Hump day");
+/// assert_eq!(Weekday::Holiday.variant_name(), "Weekday::Holiday");
+/// assert_eq!(Weekday::ALL.len(), 5);
+/// assert_eq!(Weekday::iter().rev().next(), Some(Weekday::Friday));
+/// assert_eq!(Weekday::Friday.next(), Weekday::Monday);
+/// assert_eq!(Weekday::Monday.prev(), Weekday::Friday);
+/// assert_eq!(Weekday::Holiday.next(), Weekday::Monday);
+///
+/// assert_eq!(Event::<u32>::VARIANT_NAMES, ["Event::Started", "Event::Data"]);
+/// assert_eq!(Event::Data(42).variant_name(), "Event::Data");
+/// assert_eq!(format!("{:?}", Event::<u32>::Started), Event::<u32>::Started.variant_name());
+/// ```
+#[inline]
+#[proc_macro_derive(VariantMeta, attributes(better_derive, variant_meta))]
+pub fn variant_meta(input: TokenStream) -> TokenStream { variant_meta::variant_meta(input) }
 
 
 
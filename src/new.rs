@@ -0,0 +1,259 @@
+//  NEW.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 22:48:09
+//  Last edited:
+//    19 Oct 2026, 10:11:54
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `New`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens as _, format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned as _;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, Meta, Path, Token, Type, parse_macro_input};
+
+use crate::common::{extract_generics_with, parse_toplevel_attrs, std_path, to_snake_case, trait_bounds};
+
+
+/***** HELPERS *****/
+/// Defines the toplevel attributes of the `New`-macro.
+enum NewAttr {
+    /// Generates the constructors as `const fn`s.
+    Const,
+}
+impl Parse for NewAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // NOTE: `const` is a keyword, so we parse it separately
+        if input.peek(Token![const]) {
+            input.parse::<Token![const]>()?;
+            return Ok(Self::Const);
+        }
+        let ident: Ident = input.parse()?;
+        Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+    }
+}
+
+/// Defines how to initialize a particular field.
+enum FieldInit {
+    /// Take the value as a parameter of the constructor.
+    Param,
+    /// Use the field type's `Default`-impl.
+    Default,
+    /// Use the given expression.
+    Value(TokenStream2),
+}
+impl Parse for FieldInit {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "default" {
+            Ok(Self::Default)
+        } else if ident == "value" {
+            input.parse::<Token![=]>()?;
+            Ok(Self::Value(input.parse()?))
+        } else {
+            Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+
+
+/// Defines a single constructor to generate.
+struct Constructor<'f> {
+    /// The parameters of the constructor.
+    params: Vec<TokenStream2>,
+    /// The expression constructing the value.
+    body:   TokenStream2,
+    /// The field types that need to implement `Default` for that.
+    tys:    Vec<&'f Type>,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Given a list of fields, builds the idents for it.
+///
+/// This resolves both named and unnamed fields to concrete, unique idents.
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] to generate identifiers for.
+///
+/// # Returns
+/// A vector with all generate field identifiers.
+fn generate_field_idents(fields: &Fields) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| f.ident.as_ref().map(Ident::to_token_stream).unwrap_or_else(|| Ident::new(&format!("field{i}"), f.span()).to_token_stream()))
+        .collect()
+}
+
+/// Parses `#[new(default)]` or `#[new(value = ...)]` on field attributes.
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to parse.
+/// - `is_const`: Whether the constructor will be a `const fn`.
+///
+/// # Returns
+/// A [`FieldInit`] describing how to initialize the field.
+///
+/// # Errors
+/// This function fails if we failed to parse the attribute, if it's given multiple times, or if
+/// `#[new(default)]` is given for a `const fn`.
+fn parse_field_attrs(attrs: &[Attribute], is_const: bool) -> Result<FieldInit, Error> {
+    let mut init: FieldInit = FieldInit::Param;
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(l) if l.path.is_ident("new") => {
+                if !matches!(init, FieldInit::Param) {
+                    return Err(Error::new(l.span(), "A field can only be given one `#[new(...)]`-attribute"));
+                }
+                init = attr.parse_args()?;
+                if is_const && matches!(init, FieldInit::Default) {
+                    return Err(Error::new(
+                        l.span(),
+                        "`#[new(default)]` cannot be used in a `const fn` (give a value using `#[new(value = ...)]` instead)",
+                    ));
+                }
+            },
+
+            // Anything else, we ignore
+            _ => continue,
+        }
+    }
+    Ok(init)
+}
+
+/// Builds a constructor for the given fields.
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] to build the constructor for.
+/// - `path`: The path to construct, e.g., `Self` or `Self::Variant`.
+/// - `is_const`: Whether the constructor will be a `const fn`.
+///
+/// # Returns
+/// A [`Constructor`] describing its parameters and body.
+///
+/// # Errors
+/// This function fails if we failed to parse the fields' attributes.
+fn build_constructor(fields: &Fields, path: TokenStream2, is_const: bool) -> Result<Constructor<'_>, Error> {
+    let mut params: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    let mut inits: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    let mut tys: Vec<&Type> = Vec::new();
+    for (ident, f) in generate_field_idents(fields).into_iter().zip(fields.iter()) {
+        let ty: &Type = &f.ty;
+        let init: TokenStream2 = match parse_field_attrs(&f.attrs, is_const)? {
+            FieldInit::Param => {
+                params.push(quote! { #ident: #ty });
+                ident.clone()
+            },
+            FieldInit::Default => {
+                tys.push(ty);
+                quote! { <#ty as ::std::default::Default>::default() }
+            },
+            FieldInit::Value(expr) => quote! { #expr },
+        };
+        inits.push(match &f.ident {
+            Some(_) => quote! { #ident: #init },
+            None => init,
+        });
+    }
+
+    // Build the expression
+    let body: TokenStream2 = match fields {
+        Fields::Named(_) => quote! { #path { #(#inits),* } },
+        Fields::Unnamed(_) => quote! { #path(#(#inits),*) },
+        Fields::Unit => path,
+    };
+    Ok(Constructor { params, body, tys })
+}
+
+/// Builds the name of the constructor of a variant, which is its name in `snake_case`.
+///
+/// # Arguments
+/// - `variant_name`: The name of the variant.
+///
+/// # Returns
+/// The name of the constructor. If it's a keyword (e.g., `type` for `Type`), it's a raw identifier
+/// instead.
+///
+/// # Errors
+/// This function fails if the name is a keyword that can't be a raw identifier (e.g., `self`).
+fn constructor_ident(variant_name: &Ident) -> Result<Ident, Error> {
+    let name: String = to_snake_case(&variant_name.to_string());
+    if syn::parse_str::<Ident>(&name).is_ok() {
+        Ok(Ident::new(&name, variant_name.span()))
+    } else if matches!(name.as_str(), "crate" | "self" | "super") {
+        Err(Error::new(variant_name.span(), format!("Cannot generate a constructor named `{name}` for this variant, as that is a reserved keyword")))
+    } else {
+        Ok(Ident::new_raw(&name, variant_name.span()))
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `New` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn new(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Parse the toplevel attributes
+    let (toplevel, attrs) = match parse_toplevel_attrs::<NewAttr>("new", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let is_const: bool = attrs.iter().any(|attr| matches!(attr, NewAttr::Const));
+    let constness: Option<Token![const]> = if is_const { Some(Default::default()) } else { None };
+
+    // Collect the constructors to generate
+    let name = &input.ident;
+    let sname = name.to_string();
+    let mut constructors: Vec<(Ident, String, Constructor)> = Vec::new();
+    match &input.data {
+        Data::Struct(s) => match build_constructor(&s.fields, quote! { Self }, is_const) {
+            Ok(cons) => constructors.push((format_ident!("new"), format!("Creates a new [`{sname}`]."), cons)),
+            Err(err) => return err.into_compile_error().into(),
+        },
+        Data::Enum(e) => {
+            for variant in &e.variants {
+                let variant_name = &variant.ident;
+                match constructor_ident(variant_name).and_then(|fn_name| Ok((fn_name, build_constructor(&variant.fields, quote! { Self::#variant_name }, is_const)?))) {
+                    Ok((fn_name, cons)) => constructors.push((fn_name, format!("Creates a new [`{sname}::{variant_name}`]."), cons)),
+                    Err(err) => return err.into_compile_error().into(),
+                }
+            }
+        },
+        Data::Union(_) => return Error::new(input.ident.span(), "The `New` derive macro can only be used on structs and enums").into_compile_error().into(),
+    }
+
+    // Generate an impl for every constructor, as they all have different bounds
+    let target: Path = std_path("default", "Default");
+    let impls = constructors.into_iter().map(|(fn_name, doc, Constructor { params, body, tys })| {
+        let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, tys.into_iter().map(|ty| (ty, trait_bounds(&target))));
+        quote! {
+            impl #impl_gen #name #ty_gen #where_clause {
+                #[doc = #doc]
+                #[inline]
+                pub #constness fn #fn_name(#(#params),*) -> Self { #body }
+            }
+        }
+    });
+    quote! { #(#impls)* }.into()
+}
@@ -0,0 +1,302 @@
+//  OPS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 19:51:26
+//  Last edited:
+//    19 Oct 2026, 10:04:13
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the arithmetic- and bitwise operator macros (`Add`, `AddAssign`, `Neg`, etc).
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{AssocType, Data, DeriveInput, Error, Fields, GenericArgument, Ident, Path, Token, Type, TypeParamBound, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, member, parse_toplevel_attrs, path_with_args, std_path, trait_bounds};
+
+
+/***** HELPERS *****/
+/// Defines the shape of an operator trait.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum OpKind {
+    /// It's a binary operator, e.g., `a + b`.
+    Binary,
+    /// It's a unary operator, e.g., `-a`.
+    Unary,
+    /// It's an assigning operator, e.g., `a += b`.
+    Assign,
+}
+
+
+
+/// Defines the operator traits we can derive.
+#[derive(Clone, Copy)]
+pub enum Op {
+    /// `Add`
+    Add,
+    /// `AddAssign`
+    AddAssign,
+    /// `BitAnd`
+    BitAnd,
+    /// `BitAndAssign`
+    BitAndAssign,
+    /// `BitOr`
+    BitOr,
+    /// `BitOrAssign`
+    BitOrAssign,
+    /// `BitXor`
+    BitXor,
+    /// `BitXorAssign`
+    BitXorAssign,
+    /// `Div`
+    Div,
+    /// `DivAssign`
+    DivAssign,
+    /// `Mul`
+    Mul,
+    /// `MulAssign`
+    MulAssign,
+    /// `Neg`
+    Neg,
+    /// `Not`
+    Not,
+    /// `Rem`
+    Rem,
+    /// `RemAssign`
+    RemAssign,
+    /// `Shl`
+    Shl,
+    /// `ShlAssign`
+    ShlAssign,
+    /// `Shr`
+    Shr,
+    /// `ShrAssign`
+    ShrAssign,
+    /// `Sub`
+    Sub,
+    /// `SubAssign`
+    SubAssign,
+}
+impl Op {
+    /// Returns the name of the trait in `std::ops`.
+    #[inline]
+    fn trait_name(self) -> &'static str {
+        match self {
+            Self::Add => "Add",
+            Self::AddAssign => "AddAssign",
+            Self::BitAnd => "BitAnd",
+            Self::BitAndAssign => "BitAndAssign",
+            Self::BitOr => "BitOr",
+            Self::BitOrAssign => "BitOrAssign",
+            Self::BitXor => "BitXor",
+            Self::BitXorAssign => "BitXorAssign",
+            Self::Div => "Div",
+            Self::DivAssign => "DivAssign",
+            Self::Mul => "Mul",
+            Self::MulAssign => "MulAssign",
+            Self::Neg => "Neg",
+            Self::Not => "Not",
+            Self::Rem => "Rem",
+            Self::RemAssign => "RemAssign",
+            Self::Shl => "Shl",
+            Self::ShlAssign => "ShlAssign",
+            Self::Shr => "Shr",
+            Self::ShrAssign => "ShrAssign",
+            Self::Sub => "Sub",
+            Self::SubAssign => "SubAssign",
+        }
+    }
+
+    /// Returns the name of the trait's method, which doubles as the name of the macro's attribute.
+    #[inline]
+    fn method_name(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::AddAssign => "add_assign",
+            Self::BitAnd => "bitand",
+            Self::BitAndAssign => "bitand_assign",
+            Self::BitOr => "bitor",
+            Self::BitOrAssign => "bitor_assign",
+            Self::BitXor => "bitxor",
+            Self::BitXorAssign => "bitxor_assign",
+            Self::Div => "div",
+            Self::DivAssign => "div_assign",
+            Self::Mul => "mul",
+            Self::MulAssign => "mul_assign",
+            Self::Neg => "neg",
+            Self::Not => "not",
+            Self::Rem => "rem",
+            Self::RemAssign => "rem_assign",
+            Self::Shl => "shl",
+            Self::ShlAssign => "shl_assign",
+            Self::Shr => "shr",
+            Self::ShrAssign => "shr_assign",
+            Self::Sub => "sub",
+            Self::SubAssign => "sub_assign",
+        }
+    }
+
+    /// Returns the shape of the trait.
+    #[inline]
+    fn kind(self) -> OpKind {
+        match self {
+            Self::Neg | Self::Not => OpKind::Unary,
+            Self::AddAssign
+            | Self::BitAndAssign
+            | Self::BitOrAssign
+            | Self::BitXorAssign
+            | Self::DivAssign
+            | Self::MulAssign
+            | Self::RemAssign
+            | Self::ShlAssign
+            | Self::ShrAssign
+            | Self::SubAssign => OpKind::Assign,
+            Self::Add | Self::BitAnd | Self::BitOr | Self::BitXor | Self::Div | Self::Mul | Self::Rem | Self::Shl | Self::Shr | Self::Sub => {
+                OpKind::Binary
+            },
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Checks whether a field type is a marker, i.e., `PhantomData`.
+///
+/// Such fields are simply re-created instead of operated on.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to check.
+///
+/// # Returns
+/// True if the type is (syntactically) `PhantomData<...>`, false otherwise.
+fn is_marker(ty: &Type) -> bool {
+    match ty {
+        Type::Path(p) => p.qself.is_none() && p.path.segments.last().map(|s| s.ident == "PhantomData").unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Builds the bounds for a field of the given type.
+///
+/// # Arguments
+/// - `op`: The [`Op`] we're deriving.
+/// - `target`: The [`Path`] to the operator's trait.
+/// - `ty`: The field's type.
+///
+/// # Returns
+/// The field type and bounds that ensure it can be operated on, e.g., `TYPE: Add<Output = TYPE>`.
+fn field_bounds<'t>(op: Op, target: &Path, ty: &'t Type) -> (&'t Type, Punctuated<TypeParamBound, Token![+]>) {
+    let path: Path = if op.kind() != OpKind::Assign {
+        // Also ensure the output is the same
+        path_with_args(
+            target.clone(),
+            Some(GenericArgument::AssocType(AssocType {
+                ident:    Ident::new("Output", Span::call_site()),
+                generics: None,
+                eq_token: Default::default(),
+                ty:       ty.clone(),
+            })),
+        )
+    } else {
+        target.clone()
+    };
+    (ty, trait_bounds(&path))
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the operator derive macros.
+///
+/// # Arguments
+/// - `op`: The [`Op`] to derive.
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn ops(op: Op, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let trait_name = Ident::new(op.trait_name(), Span::call_site());
+    let method = Ident::new(op.method_name(), Span::call_site());
+
+    // Only structs are supported
+    let fields: &Fields = match &input.data {
+        Data::Struct(s) => &s.fields,
+        Data::Enum(_) | Data::Union(_) => {
+            return Error::new(input.ident.span(), format!("The `{trait_name}` derive macro can only be used on structs")).into_compile_error().into();
+        },
+    };
+
+    // Generate the operation for every field
+    let target: Path = std_path("ops", op.trait_name());
+    let mut exprs: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    let mut bounds = Vec::with_capacity(fields.len());
+    for (i, f) in fields.iter().enumerate() {
+        let member: TokenStream2 = member(fields, i);
+        if is_marker(&f.ty) {
+            // Markers are simply created anew (and don't need to be assigned)
+            if op.kind() != OpKind::Assign {
+                exprs.push(quote! { #member: ::std::marker::PhantomData });
+            }
+            continue;
+        }
+        exprs.push(match op.kind() {
+            OpKind::Binary => quote! { #member: ::std::ops::#trait_name::#method(self.#member, __rhs.#member) },
+            OpKind::Unary => quote! { #member: ::std::ops::#trait_name::#method(self.#member) },
+            OpKind::Assign => quote! { ::std::ops::#trait_name::#method(&mut self.#member, __rhs.#member); },
+        });
+        bounds.push(field_bounds(op, &target, &f.ty));
+    }
+
+    // Extract the generics for the impl
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>(op.method_name(), &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, bounds);
+
+    // Done, build the impl
+    let name = &input.ident;
+    match op.kind() {
+        OpKind::Binary => quote! {
+            impl #impl_gen ::std::ops::#trait_name for #name #ty_gen #where_clause {
+                type Output = Self;
+
+                #[inline]
+                fn #method(self, __rhs: Self) -> Self {
+                    Self { #(#exprs),* }
+                }
+            }
+        },
+        OpKind::Unary => quote! {
+            impl #impl_gen ::std::ops::#trait_name for #name #ty_gen #where_clause {
+                type Output = Self;
+
+                #[inline]
+                fn #method(self) -> Self {
+                    Self { #(#exprs),* }
+                }
+            }
+        },
+        OpKind::Assign => quote! {
+            impl #impl_gen ::std::ops::#trait_name for #name #ty_gen #where_clause {
+                #[inline]
+                fn #method(&mut self, __rhs: Self) {
+                    #(#exprs)*
+                }
+            }
+        },
+    }
+    .into()
+}
@@ -187,9 +187,9 @@ pub fn partial_eq(input: TokenStream) -> TokenStream {
         leading_colon: Some(Default::default()),
         segments:      {
             let mut segments = Punctuated::new();
-            segments.push(PathSegment { ident: Ident::new("std".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("cmp".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("PartialEq".into(), Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("cmp", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("PartialEq", Span::call_site()), arguments: PathArguments::None });
             segments
         },
     }) {
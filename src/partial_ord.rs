@@ -18,11 +18,9 @@ use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{ToTokens, quote};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned as _;
-use syn::{
-    Data, DeriveInput, Error, Expr, ExprLit, Field, Fields, Ident, Lit, LitInt, Path, PathArguments, PathSegment, Token, Type, parse_macro_input,
-};
+use syn::{Data, DeriveInput, Error, Field, Fields, Ident, LitInt, Path, PathArguments, PathSegment, Token, Type, parse_macro_input};
 
-use crate::common::{extract_generics, filter_skipped_variants_and_fields};
+use crate::common::{extract_generics, filter_skipped_variants_and_fields, parse_discriminants};
 
 
 /***** HELPER FUNCTIONS *****/
@@ -101,23 +99,13 @@ fn build_partial_cmp_impl(input: &DeriveInput) -> Result<TokenStream2, Error> {
     match &input.data {
         Data::Enum(e) => {
             // Build the impls for every variant
-            let mut discriminants: Vec<(isize, TokenStream2)> = Vec::with_capacity(e.variants.len());
+            let mut discriminants: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
             let mut variants: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
-            for variant in &e.variants {
+            for (variant, discriminant) in e.variants.iter().zip(parse_discriminants(&e.variants)?) {
                 let variant_name = &variant.ident;
 
                 // First we create a match pattern for finding the variant's discriminator
-                let prev_discriminant: isize = discriminants.last().map(|(d, _)| *d).unwrap_or(0);
-                let discriminant: isize = variant
-                    .discriminant
-                    .as_ref()
-                    .map(|(_, expr)| match expr {
-                        Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => i.base10_parse(),
-                        expr => Err(Error::new(expr.span(), "Expected an integer literal")),
-                    })
-                    .transpose()?
-                    .unwrap_or(prev_discriminant + 1);
-                discriminants.push((discriminant, quote! { Self::#variant_name { .. } => #discriminant, }));
+                discriminants.push(quote! { Self::#variant_name { .. } => #discriminant, });
 
                 // Write depending on the variant form
                 variants.push(match &variant.fields {
@@ -156,7 +144,6 @@ fn build_partial_cmp_impl(input: &DeriveInput) -> Result<TokenStream2, Error> {
                     },
                 });
             }
-            let discriminants: Vec<TokenStream2> = discriminants.into_iter().map(|(_, t)| t).collect();
 
             // Build the full match
             if !variants.is_empty() {
@@ -222,9 +209,9 @@ pub fn partial_ord(input: TokenStream) -> TokenStream {
         leading_colon: Some(Default::default()),
         segments:      {
             let mut segments = Punctuated::new();
-            segments.push(PathSegment { ident: Ident::new("std".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("cmp".into(), Span::call_site()), arguments: PathArguments::None });
-            segments.push(PathSegment { ident: Ident::new("PartialOrd".into(), Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("cmp", Span::call_site()), arguments: PathArguments::None });
+            segments.push(PathSegment { ident: Ident::new("PartialOrd", Span::call_site()), arguments: PathArguments::None });
             segments
         },
     }) {
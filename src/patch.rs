@@ -0,0 +1,239 @@
+//  PATCH.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 06:52:18
+//  Last edited:
+//    19 Oct 2026, 10:33:45
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `Patch`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned as _;
+use syn::{Data, DeriveInput, Error, Fields, Ident, Path, Type, TypePath, parenthesized, parse_macro_input};
+
+use crate::common::{extract_generics_with, member, parse_field_attrs_with, parse_toplevel_attrs, std_path};
+
+
+/***** HELPERS *****/
+/// Defines the attributes of the `Patch`-macro, both toplevel and on fields.
+enum PatchAttr {
+    /// Forwards an attribute to the generated struct (or its field).
+    Attr(TokenStream2),
+    /// The field's type derives `Patch` too, and should be patched field-wise.
+    Nested(Ident),
+}
+impl Parse for PatchAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "attr" {
+            let content;
+            parenthesized!(content in input);
+            Ok(Self::Attr(content.parse()?))
+        } else if ident == "nested" {
+            Ok(Self::Nested(ident))
+        } else {
+            Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+
+
+/// Defines the code generated for a single field.
+struct PatchField {
+    /// The definition of the field in the patch struct.
+    def:     TokenStream2,
+    /// The statement applying the field of a patch.
+    apply:   TokenStream2,
+    /// The expression merging the field of two patches.
+    merge:   TokenStream2,
+    /// The expression initializing the field of an empty patch.
+    default: TokenStream2,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Builds the patch type of a nested field, e.g., `FooPatch<T>` for `Foo<T>`.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] of the field.
+///
+/// # Returns
+/// The [`Type`] of its patch.
+///
+/// # Errors
+/// This function fails if the type is not a path (and we can't name its patch).
+fn patch_type(ty: &Type) -> Result<Type, Error> {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) if !path.segments.is_empty() => {
+            let mut path: Path = path.clone();
+            let last = path.segments.last_mut().unwrap();
+            last.ident = format_ident!("{}Patch", last.ident);
+            Ok(Type::Path(TypePath { qself: None, path }))
+        },
+        ty => Err(Error::new(ty.span(), "Only fields with a named type (e.g., `Foo<T>`) can be marked as `#[patch(nested)]`")),
+    }
+}
+
+/// Builds the code for every field of the struct.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to build the patch for.
+///
+/// # Returns
+/// The fields of the struct, and a [`PatchField`] for every one of them.
+///
+/// # Errors
+/// This function fails if the input is not a struct, if we failed to parse the fields'
+/// attributes, or if a field is skipped.
+fn build_patch_fields(input: &DeriveInput) -> Result<(&Fields, Vec<PatchField>), Error> {
+    let fields: &Fields = match &input.data {
+        Data::Struct(s) => &s.fields,
+        Data::Enum(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), "The `Patch` derive macro can only be used on structs")),
+    };
+
+    // Generate the code for every field
+    let mut res: Vec<PatchField> = Vec::with_capacity(fields.len());
+    for (i, f) in fields.iter().enumerate() {
+        let member: TokenStream2 = member(fields, i);
+        let docs = f.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+        let vis = &f.vis;
+        let name = f.ident.as_ref().map(|ident| quote! { #ident: });
+        let ty: &Type = &f.ty;
+        let (skip, attrs) = parse_field_attrs_with::<PatchAttr>("patch", &f.attrs)?;
+        if skip {
+            return Err(Error::new(f.span(), "Fields cannot be skipped by the `Patch` derive macro"));
+        }
+        let mut nested: bool = false;
+        let mut fwd: Vec<TokenStream2> = Vec::new();
+        for attr in attrs {
+            match attr {
+                PatchAttr::Attr(attr) => fwd.push(attr),
+                PatchAttr::Nested(_) => nested = true,
+            }
+        }
+        res.push(if nested {
+            let patch_ty: Type = patch_type(ty)?;
+            PatchField {
+                def:     quote! { #(#docs)* #(#[#fwd])* #vis #name #patch_ty },
+                apply:   quote! { <#ty>::apply(&mut self.#member, __patch.#member); },
+                merge:   quote! { #name <#patch_ty>::merge(self.#member, __other.#member) },
+                default: quote! { #name <#patch_ty as ::std::default::Default>::default() },
+            }
+        } else {
+            PatchField {
+                def:     quote! { #(#docs)* #(#[#fwd])* #vis #name ::std::option::Option<#ty> },
+                apply:   quote! {
+                    if let ::std::option::Option::Some(__value) = __patch.#member {
+                        self.#member = __value;
+                    }
+                },
+                merge:   quote! { #name ::std::option::Option::or(__other.#member, self.#member) },
+                default: quote! { #name ::std::option::Option::None },
+            }
+        });
+    }
+    Ok((fields, res))
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `Patch` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the patch struct and the impls.
+pub fn patch(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Build the fields first
+    let (fields, patch_fields) = match build_patch_fields(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the impls. No need to bound anything, as we only move fields.
+    let (toplevel, attrs) = match parse_toplevel_attrs::<PatchAttr>("patch", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let mut fwd: Vec<TokenStream2> = Vec::with_capacity(attrs.len());
+    for attr in attrs {
+        match attr {
+            PatchAttr::Attr(attr) => fwd.push(attr),
+            PatchAttr::Nested(ident) => return Error::new(ident.span(), "Only fields can be marked as `#[patch(nested)]`").into_compile_error().into(),
+        }
+    }
+    let target: Path = std_path("marker", "Sized");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // Prepare the patch's definition
+    let vis = &input.vis;
+    let name = &input.ident;
+    let patch_name = format_ident!("{}Patch", name);
+    let patch_doc = format!("A patch for a [`{name}`], where every field is optional.");
+    let generics = &input.generics;
+    let def_where_clause = &input.generics.where_clause;
+    let defs = patch_fields.iter().map(|f| &f.def);
+    let def: TokenStream2 = match fields {
+        Fields::Named(_) => quote! { #vis struct #patch_name #generics #def_where_clause { #(#defs),* } },
+        Fields::Unnamed(_) => quote! { #vis struct #patch_name #generics (#(#defs),*) #def_where_clause; },
+        Fields::Unit => quote! { #vis struct #patch_name #generics #def_where_clause; },
+    };
+    let applies = patch_fields.iter().map(|f| &f.apply);
+    let merges = patch_fields.iter().map(|f| &f.merge);
+    let merged: TokenStream2 = match fields {
+        Fields::Named(_) => quote! { Self { #(#merges),* } },
+        Fields::Unnamed(_) => quote! { Self(#(#merges),*) },
+        Fields::Unit => quote! { Self },
+    };
+    let defaults = patch_fields.iter().map(|f| &f.default);
+    let empty: TokenStream2 = match fields {
+        Fields::Named(_) => quote! { Self { #(#defaults),* } },
+        Fields::Unnamed(_) => quote! { Self(#(#defaults),*) },
+        Fields::Unit => quote! { Self },
+    };
+
+    // Done, build the patch and the impls
+    quote! {
+        #[doc = #patch_doc]
+        #(#[#fwd])*
+        #def
+
+        // NOTE: Implemented by hand, as an empty patch doesn't need any of the fields' types to
+        // implement `Default`
+        impl #impl_gen ::std::default::Default for #patch_name #ty_gen #where_clause {
+            #[inline]
+            fn default() -> Self { #empty }
+        }
+
+        impl #impl_gen #patch_name #ty_gen #where_clause {
+            /// Merges two patches into one, where the fields set in `other` take precedence.
+            #[inline]
+            pub fn merge(self, __other: Self) -> Self { #merged }
+        }
+
+        impl #impl_gen #name #ty_gen #where_clause {
+            /// Overwrites the fields set in the given patch.
+            #[inline]
+            pub fn apply(&mut self, __patch: #patch_name #ty_gen) { #(#applies)* }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,216 @@
+//  PIN PROJECT.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 10:03:14
+//  Last edited:
+//    19 Oct 2026, 10:44:56
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `PinProject`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned as _;
+use syn::{
+    Data, DeriveInput, Error, Field, Fields, GenericParam, Ident, Lifetime, LifetimeParam, Meta, Path, PredicateType, Type, WherePredicate,
+    parse_macro_input, parse_quote,
+};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, has_generics, parse_toplevel_attrs, std_path, trait_bounds};
+
+
+/***** HELPERS *****/
+/// Defines the code generated for a single field.
+struct ProjectedField {
+    /// The definition of the field in the projection struct.
+    def:     TokenStream2,
+    /// The binding of the field when destructuring `self`.
+    binding: TokenStream2,
+    /// The expression projecting the bound field.
+    project: TokenStream2,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Checks whether the given struct is `#[repr(packed)]`, in which case we can't project to its
+/// fields.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to check.
+///
+/// # Errors
+/// This function fails if the struct is packed.
+fn check_not_packed(input: &DeriveInput) -> Result<(), Error> {
+    for attr in &input.attrs {
+        if let Meta::List(l) = &attr.meta {
+            if l.path.is_ident("repr") && l.tokens.clone().into_iter().any(|tt| matches!(tt, TokenTree::Ident(ident) if ident == "packed")) {
+                return Err(Error::new(l.path.span(), "The `PinProject` derive macro cannot be used on packed structs"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Builds the code for every field of the struct.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to build the projection for.
+/// - `lifetime`: The [`Lifetime`] of the pinned reference.
+///
+/// # Returns
+/// The fields of the struct, a [`ProjectedField`] for every one of them, and the types of the
+/// pinned fields.
+///
+/// # Errors
+/// This function fails if the input is not a struct with fields, or if it's packed.
+fn build_projected_fields<'i>(input: &'i DeriveInput, lifetime: &Lifetime) -> Result<(&'i Fields, Vec<ProjectedField>, Vec<&'i Type>), Error> {
+    let fields: &Fields = match &input.data {
+        Data::Struct(s) if !s.fields.is_empty() => &s.fields,
+        Data::Struct(_) => return Err(Error::new(input.ident.span(), "The `PinProject` derive macro can only be used on structs with fields")),
+        Data::Enum(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), "The `PinProject` derive macro can only be used on structs")),
+    };
+    check_not_packed(input)?;
+
+    // Generate the code for every field
+    let mut res: Vec<ProjectedField> = Vec::with_capacity(fields.len());
+    let mut pinned: Vec<&Type> = Vec::new();
+    for (i, f) in fields.iter().enumerate() {
+        let Field { attrs, vis, ident, ty, .. } = f;
+        let docs = attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+        let name = ident.as_ref().map(|ident| quote! { #ident: });
+        let binding: Ident = ident.clone().unwrap_or_else(|| format_ident!("__f{}", i));
+        res.push(if attrs.iter().any(|attr| matches!(&attr.meta, Meta::Path(p) if p.is_ident("pin"))) {
+            pinned.push(ty);
+            ProjectedField {
+                def:     quote! { #(#docs)* #vis #name ::std::pin::Pin<&#lifetime mut #ty> },
+                binding: quote! { #binding },
+                project: quote! { #name ::std::pin::Pin::new_unchecked(#binding) },
+            }
+        } else {
+            ProjectedField {
+                def:     quote! { #(#docs)* #vis #name &#lifetime mut #ty },
+                binding: quote! { #binding },
+                project: quote! { #name #binding },
+            }
+        });
+    }
+    Ok((fields, res, pinned))
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `PinProject` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the projection struct and the impls.
+pub fn pin_project(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let lifetime = Lifetime::new("'__pin", Span::call_site());
+
+    // Build the fields first
+    let (fields, projected, pinned) = match build_projected_fields(&input, &lifetime) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("pin_project", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Projecting only moves references around, so that needs no bounds
+    let target: Path = std_path("marker", "Sized");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // The struct may only be `Unpin` if all pinned fields are. Those using generics are bound as
+    // usual, but the others must be bound too. Because Rust refuses bounds that are trivially false
+    // (e.g., `PhantomPinned: Unpin`), we make them depend on an otherwise unused lifetime instead.
+    let unpin: Path = std_path("marker", "Unpin");
+    let (mut unpin_impl_gen, _, mut unpin_where_clause) = extract_generics_with(&toplevel, &input, &unpin, pinned.iter().map(|ty| (*ty, trait_bounds(&unpin))));
+    let mut has_lifetime: bool = false;
+    for ty in pinned {
+        if !has_generics(ty, &input.generics.params) {
+            let bounded_ty: Type = parse_quote! { (::std::marker::PhantomData<&#lifetime ()>, #ty) };
+            unpin_where_clause.push(WherePredicate::Type(PredicateType { lifetimes: None, bounded_ty, colon_token: Default::default(), bounds: trait_bounds(&unpin) }));
+            has_lifetime = true;
+        }
+    }
+    if has_lifetime {
+        unpin_impl_gen.push(GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    }
+
+    // Prepare the projection's definition
+    let vis = &input.vis;
+    let name = &input.ident;
+    let proj_name = format_ident!("{}Proj", name);
+    let proj_doc = format!("A projection of a pinned [`{name}`], where the fields marked with `#[pin]` are pinned too.");
+    let mut proj_generics = input.generics.clone();
+    proj_generics.params.insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    let (_, proj_ty_gen, _) = proj_generics.split_for_impl();
+    let def_where_clause = &input.generics.where_clause;
+    let defs = projected.iter().map(|f| &f.def);
+    let bindings = projected.iter().map(|f| &f.binding);
+    let projects = projected.iter().map(|f| &f.project);
+    let (def, pattern, projection): (TokenStream2, TokenStream2, TokenStream2) = match fields {
+        Fields::Named(_) => (
+            quote! { #vis struct #proj_name #proj_generics #def_where_clause { #(#defs),* } },
+            quote! { Self { #(#bindings),* } },
+            quote! { #proj_name { #(#projects),* } },
+        ),
+        Fields::Unnamed(_) => (
+            quote! { #vis struct #proj_name #proj_generics (#(#defs),*) #def_where_clause; },
+            quote! { Self(#(#bindings),*) },
+            quote! { #proj_name(#(#projects),*) },
+        ),
+        Fields::Unit => unreachable!(),
+    };
+
+    // Done, build the projection and the impls
+    quote! {
+        #[doc = #proj_doc]
+        #[allow(dead_code)]
+        #def
+
+        impl #impl_gen #name #ty_gen #where_clause {
+            /// Projects a pinned reference to this struct to (pinned) references to its fields.
+            ///
+            /// Fields marked with `#[pin]` are projected to a pinned reference, and any others to an
+            /// ordinary mutable one.
+            #[inline]
+            pub fn project<#lifetime>(self: ::std::pin::Pin<&#lifetime mut Self>) -> #proj_name #proj_ty_gen {
+                // SAFETY: The pinned fields are never moved out of their pin, as we only hand them
+                // out pinned. Further, the `Unpin`- and `Drop`-impls below guarantee that they stay
+                // pinned for as long as the struct is.
+                unsafe {
+                    let #pattern = ::std::pin::Pin::get_unchecked_mut(self);
+                    #projection
+                }
+            }
+        }
+
+        impl #unpin_impl_gen ::std::marker::Unpin for #name #ty_gen #unpin_where_clause {}
+
+        // Implementing `Drop` would allow moving pinned fields through `&mut self`, so we make
+        // sure it conflicts with this impl
+        const _: () = {
+            trait __MustNotImplDrop {}
+            #[allow(drop_bounds)]
+            impl<__T: ::std::ops::Drop> __MustNotImplDrop for __T {}
+            impl #impl_gen __MustNotImplDrop for #name #ty_gen #where_clause {}
+        };
+    }
+    .into()
+}
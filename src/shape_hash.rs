@@ -0,0 +1,255 @@
+//  SHAPE HASH.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 07:58:31
+//  Last edited:
+//    19 Oct 2026, 10:35:10
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `ShapeHash`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::{Delimiter, TokenStream as TokenStream2, TokenTree};
+use quote::{ToTokens as _, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, Path, Type, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, parse_field_attrs, parse_field_attrs_with, parse_toplevel_attrs, std_path};
+
+
+/***** CONSTANTS *****/
+/// The offset basis of the 64-bit FNV-1a hash.
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+/// The prime of the 64-bit FNV-1a hash.
+const FNV_PRIME: u64 = 0x00000100000001b3;
+
+
+
+
+
+/***** HELPERS *****/
+/// Defines the attributes of the `ShapeHash`-macro on fields.
+enum ShapeAttr {
+    /// The field's type derives `ShapeHash` too, and its hash should be mixed in.
+    Nested,
+}
+impl Parse for ShapeAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "nested" {
+            Ok(Self::Nested)
+        } else {
+            Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Hashes the given string using 64-bit FNV-1a.
+///
+/// We don't use the [`Hasher`](std::hash::Hasher)s of the standard library, as their output is
+/// not guaranteed to be stable across compiler versions.
+///
+/// # Arguments
+/// - `shape`: The string to hash.
+///
+/// # Returns
+/// The hash of `shape`.
+fn fnv1a(shape: &str) -> u64 {
+    let mut hash: u64 = FNV_OFFSET;
+    for b in shape.bytes() {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Writes a list of tokens (e.g., a type or an expression) to a shape.
+///
+/// We don't use the [`Display`](std::fmt::Display)-impl of [`TokenStream2`] for this, as its
+/// formatting (e.g., where it puts spaces) isn't guaranteed to be stable across compiler versions.
+/// Instead, every token is written followed by a single space.
+///
+/// # Arguments
+/// - `tokens`: The [`TokenStream2`] to describe.
+/// - `shape`: The string to write the shape to.
+fn write_tokens_shape(tokens: TokenStream2, shape: &mut String) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(g) => {
+                let (open, close): (&str, &str) = match g.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => ("", ""),
+                };
+                shape.push_str(open);
+                shape.push(' ');
+                write_tokens_shape(g.stream(), shape);
+                shape.push_str(close);
+            },
+            TokenTree::Ident(ident) => shape.push_str(&ident.to_string()),
+            TokenTree::Punct(punct) => shape.push(punct.as_char()),
+            TokenTree::Literal(lit) => shape.push_str(&lit.to_string()),
+        }
+        shape.push(' ');
+    }
+}
+
+/// Parses the attributes of a field or variant.
+///
+/// Next to `#[shape_hash(skip)]`, this also honours `#[serialize(skip)]`, as that won't be part
+/// of serialized values either.
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to parse.
+///
+/// # Returns
+/// Whether it was skipped, and whether it was marked as nested.
+///
+/// # Errors
+/// This function fails if we failed to parse the attributes.
+fn parse_shape_attrs(attrs: &[Attribute]) -> Result<(bool, bool), Error> {
+    let (skip, custom) = parse_field_attrs_with::<ShapeAttr>("shape_hash", attrs)?;
+    let skip: bool = skip || parse_field_attrs("serialize", attrs)?;
+    Ok((skip, custom.iter().any(|attr| matches!(attr, ShapeAttr::Nested))))
+}
+
+/// Writes the shape of a list of fields.
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] to describe.
+/// - `shape`: The string to write the shape to.
+/// - `nested`: A list to push the types of nested fields to.
+///
+/// # Errors
+/// This function fails if we failed to parse the fields' attributes.
+fn write_fields_shape<'f>(fields: &'f Fields, shape: &mut String, nested: &mut Vec<&'f Type>) -> Result<(), Error> {
+    let (open, close): (&str, &str) = match fields {
+        Fields::Named(_) => ("{", "}"),
+        Fields::Unnamed(_) => ("(", ")"),
+        Fields::Unit => return Ok(()),
+    };
+
+    // NOTE: Unnamed fields are only identified by their order, so skipping one doesn't change
+    // the shape of the others
+    shape.push_str(open);
+    for f in fields {
+        let (skip, is_nested) = parse_shape_attrs(&f.attrs)?;
+        if skip {
+            continue;
+        }
+        if let Some(ident) = &f.ident {
+            shape.push_str(&ident.to_string());
+            shape.push(':');
+        }
+        write_tokens_shape(f.ty.to_token_stream(), shape);
+        if is_nested {
+            shape.push_str(" nested");
+            nested.push(&f.ty);
+        }
+        shape.push(';');
+    }
+    shape.push_str(close);
+    Ok(())
+}
+
+/// Writes the shape of the given type.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to describe.
+///
+/// # Returns
+/// The shape of the type, and the types of its nested fields.
+///
+/// # Errors
+/// This function fails if we failed to parse the fields' or variants' attributes, or if a variant
+/// is marked as nested.
+fn build_shape(input: &DeriveInput) -> Result<(String, Vec<&Type>), Error> {
+    let mut nested: Vec<&Type> = Vec::new();
+    let shape: String = match &input.data {
+        Data::Enum(e) => {
+            let mut shape: String = format!("enum {}{{", input.ident);
+            for variant in &e.variants {
+                let (skip, is_nested) = parse_shape_attrs(&variant.attrs)?;
+                if is_nested {
+                    return Err(Error::new(variant.ident.span(), "Only fields can be marked as `#[shape_hash(nested)]`"));
+                }
+                if skip {
+                    continue;
+                }
+                shape.push_str(&variant.ident.to_string());
+                if let Some((_, discriminant)) = &variant.discriminant {
+                    shape.push('=');
+                    write_tokens_shape(discriminant.to_token_stream(), &mut shape);
+                }
+                write_fields_shape(&variant.fields, &mut shape, &mut nested)?;
+                shape.push(',');
+            }
+            shape.push('}');
+            shape
+        },
+        Data::Struct(s) => {
+            let mut shape: String = format!("struct {}", input.ident);
+            write_fields_shape(&s.fields, &mut shape, &mut nested)?;
+            shape
+        },
+        Data::Union(_) => return Err(Error::new(input.ident.span(), "The `ShapeHash` derive macro can only be used on structs and enums")),
+    };
+    Ok((shape, nested))
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `ShapeHash` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn shape_hash(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Describe the shape of the type first, then mix in the nested hashes in the generated code
+    let (shape, nested) = match build_shape(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let hash: u64 = fnv1a(&shape);
+    let hash: TokenStream2 = nested.into_iter().fold(quote! { #hash }, |hash, ty| quote! { (#hash ^ <#ty>::SHAPE_HASH).wrapping_mul(#FNV_PRIME) });
+
+    // Extract the generics for the impl. No need to bound anything, as we don't touch the fields.
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("shape_hash", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("marker", "Sized");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // Done, build the impl
+    let name = &input.ident;
+    quote! {
+        impl #impl_gen #name #ty_gen #where_clause {
+            /// A fingerprint of the shape of this type, i.e., its name and those of its fields and
+            /// variants, the types of its fields and its discriminants.
+            ///
+            /// It changes whenever the shape changes, which makes it suitable to version serialized
+            /// values. It doesn't depend on the compiler version or platform, nor on how the type
+            /// is formatted.
+            pub const SHAPE_HASH: u64 = #hash;
+        }
+    }
+    .into()
+}
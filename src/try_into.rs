@@ -0,0 +1,156 @@
+//  TRY INTO.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 15:21:04
+//  Last edited:
+//    19 Oct 2026, 09:36:48
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `TryInto`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens as _, quote};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::{Data, DeriveInput, Error, Field, Fields, GenericParam, Ident, Lifetime, LifetimeParam, Path, Token, Type, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, bare_type_param, extract_generics_with, parse_toplevel_attrs, single_field_variants, std_path};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Given a list of fields, builds the idents for it.
+///
+/// This resolves both named and unnamed fields to concrete, unique idents.
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] to generate identifiers for.
+///
+/// # Returns
+/// A vector with all generate field identifiers.
+fn generate_field_idents(fields: &Punctuated<Field, Token![,]>) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| f.ident.as_ref().map(Ident::to_token_stream).unwrap_or_else(|| Ident::new(&format!("field{i}"), f.span()).to_token_stream()))
+        .collect()
+}
+
+/// Collects the types to generate `TryFrom`-impls for.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to collect the impls of.
+///
+/// # Returns
+/// A list of `(type, pattern, binding)`-triplets, where the pattern matches the variant and binds
+/// its field to the binding.
+///
+/// # Errors
+/// This function fails if the input is not an enum, if we failed to parse the
+/// `#[try_into(skip)]`-attributes, or if two variants would generate conflicting impls or one
+/// has a bare type parameter as field.
+fn build_try_into_impls(input: &DeriveInput) -> Result<Vec<(&Type, TokenStream2, TokenStream2)>, Error> {
+    let name = &input.ident;
+    match &input.data {
+        Data::Enum(e) => single_field_variants("try_into", "TryFrom", &e.variants, &input.generics.params)?
+            .into_iter()
+            .map(|(variant, f)| {
+                let variant_name = &variant.ident;
+                // NOTE: Rust doesn't allow implementing a foreign trait for any `T`
+                if let Some(param) = bare_type_param(&f.ty, &input.generics.params) {
+                    return Err(Error::new(
+                        f.ty.span(),
+                        format!(
+                            "Cannot generate a `TryFrom`-impl for variant `{variant_name}`, as its field is the bare type parameter `{param}` (use \
+                             `#[try_into(skip)]` to skip it)"
+                        ),
+                    ));
+                }
+                let (pattern, binding): (TokenStream2, TokenStream2) = match &variant.fields {
+                    Fields::Named(n) => {
+                        let fields = generate_field_idents(&n.named);
+                        (quote! { #name::#variant_name { #(#fields),* } }, fields[0].clone())
+                    },
+                    Fields::Unnamed(u) => {
+                        let fields = generate_field_idents(&u.unnamed);
+                        (quote! { #name::#variant_name(#(#fields),*) }, fields[0].clone())
+                    },
+                    Fields::Unit => unreachable!(),
+                };
+                Ok((&f.ty, pattern, binding))
+            })
+            .collect(),
+        Data::Struct(_) | Data::Union(_) => Err(Error::new(input.ident.span(), "The `TryInto` derive macro can only be used on enums")),
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `TryInto` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn try_into(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Collect the variants to convert to
+    let impls = match build_try_into_impls(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the impls. No need to bound the fields, as they are simply moved.
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("try_into", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("convert", "TryFrom");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+    // NOTE: The by-reference impls need an additional lifetime for the reference
+    let lifetime = Lifetime::new("'__a", Span::call_site());
+    let (mut ref_impl_gen, _, _) = extract_generics_with(&toplevel, &input, &target, None);
+    ref_impl_gen.push(GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+
+    // Done, build the impls
+    let name = &input.ident;
+    let impls = impls.into_iter().map(|(ty, pattern, binding)| {
+        quote! {
+            impl #impl_gen ::std::convert::TryFrom<#name #ty_gen> for #ty #where_clause {
+                type Error = #name #ty_gen;
+
+                #[inline]
+                fn try_from(__value: #name #ty_gen) -> ::std::result::Result<Self, #name #ty_gen> {
+                    match __value {
+                        #pattern => ::std::result::Result::Ok(#binding),
+                        #[allow(unreachable_patterns)]
+                        __value => ::std::result::Result::Err(__value),
+                    }
+                }
+            }
+
+            impl #ref_impl_gen ::std::convert::TryFrom<&#lifetime #name #ty_gen> for &#lifetime #ty #where_clause {
+                type Error = &#lifetime #name #ty_gen;
+
+                #[inline]
+                fn try_from(__value: &#lifetime #name #ty_gen) -> ::std::result::Result<Self, &#lifetime #name #ty_gen> {
+                    match __value {
+                        #pattern => ::std::result::Result::Ok(#binding),
+                        #[allow(unreachable_patterns)]
+                        __value => ::std::result::Result::Err(__value),
+                    }
+                }
+            }
+        }
+    });
+    quote! { #(#impls)* }.into()
+}
@@ -0,0 +1,149 @@
+//  UNSAFE MARKER.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 08:59:46
+//  Last edited:
+//    19 Oct 2026, 10:40:27
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `UnsafeSend`- and `UnsafeSync`-macros.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Data, DeriveInput, Error, Field, Ident, Path, PredicateType, Type, WherePredicate, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, has_generics, parse_field_attrs_with, parse_toplevel_attrs, std_path, trait_bounds};
+
+
+/***** HELPERS *****/
+/// Defines the attributes of the `UnsafeSend`- and `UnsafeSync`-macros on fields.
+enum MarkerAttr {
+    /// The field is assumed to be safe to send (or share), and thus isn't bound.
+    Assume,
+}
+impl Parse for MarkerAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "assume" {
+            Ok(Self::Assume)
+        } else {
+            Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Collects the types of all fields that aren't marked as assumed.
+///
+/// # Arguments
+/// - `base_ident`: The name of the attribute to look for (e.g., `send`).
+/// - `input`: The [`DeriveInput`] to collect the field types of.
+///
+/// # Returns
+/// A list of the [`Type`]s that need to be bound.
+///
+/// # Errors
+/// This function fails if we failed to parse the fields' attributes.
+fn collect_bounded_fields<'i>(base_ident: &str, input: &'i DeriveInput) -> Result<Vec<&'i Type>, Error> {
+    let fields: Box<dyn Iterator<Item = &Field>> = match &input.data {
+        Data::Enum(e) => Box::new(e.variants.iter().flat_map(|variant| &variant.fields)),
+        Data::Struct(s) => Box::new(s.fields.iter()),
+        Data::Union(u) => Box::new(u.fields.named.iter()),
+    };
+
+    // NOTE: We deliberately don't honour `skip` here, as that's easily given for other macros.
+    // Only an explicit `assume` exempts a field.
+    let mut tys: Vec<&Type> = Vec::new();
+    for f in fields {
+        let (_, attrs) = parse_field_attrs_with::<MarkerAttr>(base_ident, &f.attrs)?;
+        if !attrs.iter().any(|attr| matches!(attr, MarkerAttr::Assume)) {
+            tys.push(&f.ty);
+        }
+    }
+    Ok(tys)
+}
+
+/// Implements an unsafe marker trait for the given input.
+///
+/// Every field is bound on the trait, unless marked as assumed. Unlike usual, this is also true for
+/// fields that don't use any generics, as those are precisely the ones to watch out for here (e.g.,
+/// raw pointers).
+///
+/// # Arguments
+/// - `base_ident`: The name of the attributes to look for (e.g., `send`).
+/// - `input`: The [`DeriveInput`] to implement the trait for.
+/// - `target`: The [`Path`] of the trait to implement.
+///
+/// # Returns
+/// A [`TokenStream2`] encoding the impl.
+///
+/// # Errors
+/// This function fails if we failed to parse the toplevel- or fields' attributes.
+fn impl_marker(base_ident: &str, input: &DeriveInput, target: Path) -> Result<TokenStream2, Error> {
+    let tys: Vec<&Type> = collect_bounded_fields(base_ident, input)?;
+    let (toplevel, _) = parse_toplevel_attrs::<NoCustomAttrs>(base_ident, &input.attrs)?;
+
+    // Bound the generic fields as usual, then add the others ourselves
+    let (impl_gen, ty_gen, mut where_clause) = extract_generics_with(&toplevel, input, &target, tys.iter().map(|ty| (*ty, trait_bounds(&target))));
+    for ty in tys {
+        if !has_generics(ty, &input.generics.params) {
+            where_clause.push(WherePredicate::Type(PredicateType {
+                lifetimes:   None,
+                bounded_ty:  ty.clone(),
+                colon_token: Default::default(),
+                bounds:      trait_bounds(&target),
+            }));
+        }
+    }
+
+    // Done, build the impl
+    let name = &input.ident;
+    Ok(quote! {
+        unsafe impl #impl_gen #target for #name #ty_gen #where_clause {}
+    })
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `UnsafeSend` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn unsafe_send(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match impl_marker("send", &input, std_path("marker", "Send")) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// Actual implementation of the `UnsafeSync` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn unsafe_sync(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match impl_marker("sync", &input, std_path("marker", "Sync")) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
@@ -0,0 +1,209 @@
+//  VARIANT HELPERS.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 01:58:44
+//  Last edited:
+//    19 Oct 2026, 10:17:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `VariantHelpers`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens as _, format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::{Data, DeriveInput, Error, Field, Fields, Ident, Path, Token, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, parse_field_attrs, parse_toplevel_attrs, std_path, to_snake_case};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Given a list of fields, builds the idents for it.
+///
+/// This resolves both named and unnamed fields to concrete, unique idents.
+///
+/// # Arguments
+/// - `fields`: The [`Fields`] to generate identifiers for.
+///
+/// # Returns
+/// A vector with all generate field identifiers.
+fn generate_field_idents(fields: &Punctuated<Field, Token![,]>) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| f.ident.as_ref().map(Ident::to_token_stream).unwrap_or_else(|| Ident::new(&format!("field{i}"), f.span()).to_token_stream()))
+        .collect()
+}
+
+/// Builds the helper methods for every variant.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to build the methods for.
+///
+/// # Returns
+/// A list of methods.
+///
+/// # Errors
+/// This function fails if the input is not an enum, or if we failed to parse the variants'
+/// attributes.
+fn build_helpers(input: &DeriveInput) -> Result<Vec<TokenStream2>, Error> {
+    let e = match &input.data {
+        Data::Enum(e) => e,
+        Data::Struct(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), "The `VariantHelpers` derive macro can only be used on enums")),
+    };
+    let sname = input.ident.to_string();
+
+    // Build the methods for every variant
+    let mut helpers: Vec<TokenStream2> = Vec::with_capacity(5 * e.variants.len() + 1);
+    let mut names: Vec<TokenStream2> = Vec::with_capacity(e.variants.len());
+    let mut needs_names: bool = false;
+    for variant in &e.variants {
+        let variant_name = &variant.ident;
+        let svariant_name = variant_name.to_string();
+        names.push(quote! { Self::#variant_name { .. } => #svariant_name, });
+        if parse_field_attrs("variant_helpers", &variant.attrs)? {
+            continue;
+        }
+        let snake = to_snake_case(&svariant_name);
+
+        // Every variant can be checked for
+        let is = format_ident!("is_{}", snake, span = variant_name.span());
+        let is_doc = format!("Returns whether this is a [`{sname}::{svariant_name}`].");
+        helpers.push(quote! {
+            #[doc = #is_doc]
+            #[inline]
+            pub const fn #is(&self) -> bool { ::std::matches!(self, Self::#variant_name { .. }) }
+        });
+
+        // Those with fields can also be projected
+        let (pattern, fields, tys): (TokenStream2, Vec<TokenStream2>, Vec<_>) = match &variant.fields {
+            Fields::Named(n) => {
+                let fields = generate_field_idents(&n.named);
+                (quote! { Self::#variant_name { #(#fields),* } }, fields, n.named.iter().map(|f| &f.ty).collect())
+            },
+            Fields::Unnamed(u) => {
+                let fields = generate_field_idents(&u.unnamed);
+                (quote! { Self::#variant_name(#(#fields),*) }, fields, u.unnamed.iter().map(|f| &f.ty).collect())
+            },
+            Fields::Unit => continue,
+        };
+        needs_names = true;
+        let (value, ty, ref_ty, mut_ty): (TokenStream2, TokenStream2, TokenStream2, TokenStream2) = if tys.len() == 1 {
+            let (field, ty) = (&fields[0], tys[0]);
+            (quote! { #field }, quote! { #ty }, quote! { &#ty }, quote! { &mut #ty })
+        } else {
+            (quote! { (#(#fields),*) }, quote! { (#(#tys),*) }, quote! { (#(&#tys),*) }, quote! { (#(&mut #tys),*) })
+        };
+
+        let as_ref = format_ident!("as_{}", snake, span = variant_name.span());
+        let as_mut = format_ident!("as_{}_mut", snake, span = variant_name.span());
+        let into = format_ident!("into_{}", snake, span = variant_name.span());
+        let unwrap = format_ident!("unwrap_{}", snake, span = variant_name.span());
+        let as_ref_doc = format!("Returns the field(s) of this [`{sname}::{svariant_name}`], or [`None`] if it's another variant.");
+        let as_mut_doc = format!("Returns the field(s) of this [`{sname}::{svariant_name}`] mutably, or [`None`] if it's another variant.");
+        let into_doc = format!("Returns the field(s) of this [`{sname}::{svariant_name}`], or itself as error if it's another variant.");
+        let unwrap_doc = format!(
+            "Returns the field(s) of this [`{sname}::{svariant_name}`].\n\n# Panics\nThis function panics if this is another variant."
+        );
+        let unwrap_msg = format!("called `{sname}::{unwrap}()` on a `{sname}::{{}}` value");
+        helpers.push(quote! {
+            #[doc = #as_ref_doc]
+            #[inline]
+            pub fn #as_ref(&self) -> ::std::option::Option<#ref_ty> {
+                match self {
+                    #pattern => ::std::option::Option::Some(#value),
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            #[doc = #as_mut_doc]
+            #[inline]
+            pub fn #as_mut(&mut self) -> ::std::option::Option<#mut_ty> {
+                match self {
+                    #pattern => ::std::option::Option::Some(#value),
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            #[doc = #into_doc]
+            #[inline]
+            pub fn #into(self) -> ::std::result::Result<#ty, Self> {
+                match self {
+                    #pattern => ::std::result::Result::Ok(#value),
+                    #[allow(unreachable_patterns)]
+                    __other => ::std::result::Result::Err(__other),
+                }
+            }
+
+            #[doc = #unwrap_doc]
+            #[inline]
+            #[track_caller]
+            pub fn #unwrap(self) -> #ty {
+                match self {
+                    #pattern => #value,
+                    #[allow(unreachable_patterns)]
+                    __other => ::std::panic!(#unwrap_msg, Self::__variant_name(&__other)),
+                }
+            }
+        });
+    }
+
+    // The `unwrap_*()`-methods need to know the name of the actual variant
+    if needs_names {
+        helpers.push(quote! {
+            #[inline]
+            fn __variant_name(&self) -> &'static str {
+                match self {
+                    #(#names)*
+                }
+            }
+        });
+    }
+    Ok(helpers)
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `VariantHelpers` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn variant_helpers(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Build the methods first
+    let helpers = match build_helpers(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // Extract the generics for the impl. No need to bound anything, as we only refer to fields.
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("variant_helpers", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("marker", "Sized");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // Done, build the impl
+    let name = &input.ident;
+    quote! {
+        impl #impl_gen #name #ty_gen #where_clause {
+            #(#helpers)*
+        }
+    }
+    .into()
+}
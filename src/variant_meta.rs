@@ -0,0 +1,213 @@
+//  VARIANT META.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 03:52:07
+//  Last edited:
+//    19 Oct 2026, 10:26:33
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `VariantMeta`-macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Data, DeriveInput, Error, Fields, Ident, LitStr, Path, Token, Variant, parse_macro_input};
+
+use crate::common::{NoCustomAttrs, extract_generics_with, parse_field_attrs_with, parse_toplevel_attrs, std_path};
+
+
+/***** HELPERS *****/
+/// Defines the attributes of the `VariantMeta`-macro on variants.
+enum VariantAttr {
+    /// Gives the variant a different name.
+    Rename(LitStr),
+}
+impl Parse for VariantAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "rename" {
+            input.parse::<Token![=]>()?;
+            Ok(Self::Rename(input.parse()?))
+        } else {
+            Err(Error::new(ident.span(), format!("Unknown attribute {ident:?}")))
+        }
+    }
+}
+
+
+
+/// Defines what we know of a single variant.
+struct VariantInfo<'v> {
+    /// The variant itself.
+    variant: &'v Variant,
+    /// Its (possibly renamed) name.
+    name:    String,
+    /// Whether it's skipped, i.e., not listed.
+    skip:    bool,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Collects the names of the variants, and whether they're skipped.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to collect the variants of.
+///
+/// # Returns
+/// A list of [`VariantInfo`]s, one for every variant in declaration order.
+///
+/// # Errors
+/// This function fails if the input is not an enum, or if we failed to parse the variants'
+/// attributes.
+fn collect_variants(input: &DeriveInput) -> Result<Vec<VariantInfo<'_>>, Error> {
+    let e = match &input.data {
+        Data::Enum(e) => e,
+        Data::Struct(_) | Data::Union(_) => return Err(Error::new(input.ident.span(), "The `VariantMeta` derive macro can only be used on enums")),
+    };
+
+    // Parse the attributes of every variant
+    let mut res: Vec<VariantInfo> = Vec::with_capacity(e.variants.len());
+    for variant in &e.variants {
+        let (skip, attrs) = parse_field_attrs_with::<VariantAttr>("variant_meta", &variant.attrs)?;
+        // NOTE: Defaults to the name printed by our `Debug` (i.e., `Enum::Variant`)
+        let mut name: String = format!("{}::{}", input.ident, variant.ident);
+        for attr in attrs {
+            match attr {
+                VariantAttr::Rename(lit) => name = lit.value(),
+            }
+        }
+        res.push(VariantInfo { variant, name, skip });
+    }
+    Ok(res)
+}
+
+/// Builds the iteration helpers for enums without fields.
+///
+/// # Arguments
+/// - `variants`: The list of [`VariantInfo`]s describing the enum.
+///
+/// # Returns
+/// The constants and methods to add, or [`None`] if any of the variants has fields.
+fn build_fieldless_helpers(variants: &[VariantInfo]) -> Option<TokenStream2> {
+    if variants.iter().any(|v| !matches!(v.variant.fields, Fields::Unit)) {
+        return None;
+    }
+    let listed: Vec<&Ident> = variants.iter().filter(|v| !v.skip).map(|v| &v.variant.ident).collect();
+    let all: Vec<&Ident> = variants.iter().map(|v| &v.variant.ident).collect();
+
+    // Find the next- and previous listed variant of every variant, cycling around. Skipped
+    // variants continue from their position; and if nothing is listed, every variant is its own.
+    let mut next_arms: Vec<TokenStream2> = Vec::with_capacity(variants.len());
+    let mut prev_arms: Vec<TokenStream2> = Vec::with_capacity(variants.len());
+    for (i, variant_name) in all.iter().enumerate() {
+        let n: usize = variants.len();
+        let next: &Ident = (1..=n).map(|d| (i + d) % n).find(|j| !variants[*j].skip).map(|j| all[j]).unwrap_or(variant_name);
+        let prev: &Ident = (1..=n).map(|d| (i + n - d) % n).find(|j| !variants[*j].skip).map(|j| all[j]).unwrap_or(variant_name);
+        next_arms.push(quote! { Self::#variant_name => Self::#next, });
+        prev_arms.push(quote! { Self::#variant_name => Self::#prev, });
+    }
+
+    Some(quote! {
+        /// All variants, in declaration order.
+        pub const ALL: &'static [Self] = &[#(Self::#listed),*];
+
+        /// Returns an iterator over all variants, in declaration order.
+        #[inline]
+        pub fn iter() -> impl ::std::iter::DoubleEndedIterator<Item = Self> + ::std::iter::ExactSizeIterator {
+            Self::ALL.iter().map(|__value| match *__value {
+                #(Self::#all => Self::#all,)*
+            })
+        }
+
+        /// Returns the variant declared after this one, starting over at the first one after the last.
+        #[inline]
+        pub const fn next(&self) -> Self {
+            match *self {
+                #(#next_arms)*
+            }
+        }
+
+        /// Returns the variant declared before this one, starting over at the last one before the first.
+        #[inline]
+        pub const fn prev(&self) -> Self {
+            match *self {
+                #(#prev_arms)*
+            }
+        }
+    })
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `VariantMeta` derive macro.
+///
+/// # Arguments
+/// - `input`: The [`TokenStream2`] describing the data container to derive for.
+///
+/// # Returns
+/// A [`TokenSream2`] encoding the impl.
+pub fn variant_meta(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Collect the variants
+    let variants = match collect_variants(&input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let names: Vec<&str> = variants.iter().filter(|v| !v.skip).map(|v| v.name.as_str()).collect();
+    let count: usize = names.len();
+    let name_arms = variants.iter().map(|VariantInfo { variant, name, .. }| {
+        let variant_name = &variant.ident;
+        quote! { Self::#variant_name { .. } => #name, }
+    });
+    let fieldless = build_fieldless_helpers(&variants);
+
+    // Extract the generics for the impl. No need to bound anything, as we don't touch the fields.
+    let (toplevel, _) = match parse_toplevel_attrs::<NoCustomAttrs>("variant_meta", &input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let target: Path = std_path("marker", "Sized");
+    let (impl_gen, ty_gen, where_clause) = extract_generics_with(&toplevel, &input, &target, None);
+
+    // Done, build the impl
+    let name = &input.ident;
+    quote! {
+        impl #impl_gen #name #ty_gen #where_clause {
+            /// The names of all variants, in declaration order.
+            ///
+            /// Variants marked with `#[variant_meta(skip)]` aren't listed.
+            pub const VARIANT_NAMES: &'static [&'static str] = &[#(#names),*];
+
+            /// The number of listed variants, i.e., the length of `VARIANT_NAMES`.
+            ///
+            /// Variants marked with `#[variant_meta(skip)]` aren't counted.
+            pub const VARIANT_COUNT: usize = #count;
+
+            /// Returns the name of this value's variant.
+            ///
+            /// This also works for variants marked with `#[variant_meta(skip)]`.
+            #[inline]
+            pub const fn variant_name(&self) -> &'static str {
+                // NOTE: Dereferenced, so that this also works for enums without variants
+                match *self {
+                    #(#name_arms)*
+                }
+            }
+
+            #fieldless
+        }
+    }
+    .into()
+}